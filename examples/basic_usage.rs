@@ -42,14 +42,14 @@ async fn main() -> anyhow::Result<()> {
     // Note: For real credit card payments, you need a card token from the frontend.
     // This example uses a generic request structure.
     let payment_request = PaymentRequest {
-        transaction_amount: 100.0,
-        payment_method_id: "pix".to_string(), // Using pix as an example of a simple method
         description: Some("Example Payment".to_string()),
-        payer: Payer {
-            email: "test_user_123@testuser.com".to_string(),
-            ..Default::default()
-        },
-        ..Default::default()
+        ..PaymentRequest::pix(
+            100.0,
+            Payer {
+                email: "test_user_123@testuser.com".to_string(),
+                ..Default::default()
+            },
+        )
     };
 
     // We can add an idempotency key to prevent double charges
@@ -69,6 +69,9 @@ async fn main() -> anyhow::Result<()> {
                 println!("Payment created successfully!");
                 println!("ID: {}", payment.id);
                 println!("Status: {}", payment.status);
+                if let Some(code) = payment.pix_qr_code() {
+                    println!("PIX copy-and-paste code: {}", code);
+                }
             } else {
                 println!("Payment failed with status: {}", res.status());
             }
@@ -0,0 +1,30 @@
+use mercadopago_sdk::blocking::MercadoPagoClient;
+use std::env;
+
+// A synchronous script: no tokio runtime, no async.
+fn main() -> anyhow::Result<()> {
+    // Load .env file
+    dotenv::dotenv().ok();
+
+    // Get access token from environment
+    let access_token = env::var("MERCADO_PAGO_ACCESS_TOKEN")
+        .expect("MERCADO_PAGO_ACCESS_TOKEN must be set in .env or environment");
+
+    let payment_id: u64 = env::args()
+        .nth(1)
+        .and_then(|id| id.parse().ok())
+        .expect("Usage: blocking_payment_status <PAYMENT_ID>");
+
+    // Initialize client
+    let client = MercadoPagoClient::builder(&access_token).build()?;
+
+    match client.get_payment(payment_id) {
+        Ok(payment) => {
+            println!("Payment {}: {}", payment.id, payment.status);
+            println!("Amount: {:.2}", payment.transaction_amount);
+        }
+        Err(e) => eprintln!("Error fetching payment: {}", e),
+    }
+
+    Ok(())
+}
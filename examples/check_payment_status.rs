@@ -1,7 +1,8 @@
 use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::wait::{PaymentOutcome, WaitOptions};
 use std::env;
 use std::time::Duration;
-use tokio::time::sleep;
+use tokio_util::sync::CancellationToken;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -25,49 +26,45 @@ async fn main() -> anyhow::Result<()> {
     }
     let external_reference = &args[1];
 
-    println!("--- Polling for Payment Status ---");
+    println!("--- Waiting for Payment Status ---");
     println!("External Reference: {}", external_reference);
-    println!(
-        "Press Ctrl+C to stop.
-"
-    );
+    println!("Press Ctrl+C to stop.\n");
 
-    loop {
-        match client.search_payments(external_reference).await {
-            Ok(search_res) => {
-                if let Some(payment) = search_res.results.first() {
-                    println!("Payment Found!");
-                    println!("   ID: {}", payment.id);
-                    println!("   Status: {}", payment.status);
-                    println!("   Status Detail: {:?}", payment.status_detail);
-
-                    if payment.status == "approved" {
-                        println!(
-                            "
-✅ SUCCESS: The payment has been accredited!"
-                        );
-                        break;
-                    } else if payment.status == "rejected" {
-                        println!(
-                            "
-❌ REJECTED: The payment was not accepted."
-                        );
-                        break;
-                    } else {
-                        println!("   -> Status is '{}'. Still waiting...", payment.status);
-                    }
-                } else {
-                    println!(
-                        "No payment found yet for reference '{}'.",
-                        external_reference
-                    );
-                }
-            }
-            Err(e) => eprintln!("Error searching payment: {}", e),
+    // Cancel the wait when the user presses Ctrl+C
+    let token = CancellationToken::new();
+    let ctrl_c_token = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            ctrl_c_token.cancel();
         }
+    });
+
+    let options = WaitOptions::new()
+        .timeout(Duration::from_secs(600))
+        .max_interval(Duration::from_secs(5))
+        .cancellation_token(token);
 
-        println!("Retrying in 5 seconds...");
-        sleep(Duration::from_secs(5)).await;
+    match client.wait_for_payment(external_reference, options).await? {
+        PaymentOutcome::Approved(payment) => {
+            println!("Payment Found!");
+            println!("   ID: {}", payment.id);
+            println!("   Status Detail: {:?}", payment.status_detail);
+            println!("\n✅ SUCCESS: The payment has been accredited!");
+        }
+        PaymentOutcome::Rejected(payment) => {
+            println!("Payment Found!");
+            println!("   ID: {}", payment.id);
+            println!("   Status: {}", payment.status);
+            println!("   Status Detail: {:?}", payment.status_detail);
+            println!("\n❌ REJECTED: The payment was not accepted.");
+        }
+        PaymentOutcome::TimedOut => {
+            println!(
+                "No final payment found for reference '{}' in time.",
+                external_reference
+            );
+        }
+        PaymentOutcome::Cancelled => println!("Stopped."),
     }
 
     Ok(())
@@ -13,11 +13,8 @@ async fn main() -> anyhow::Result<()> {
     // Initialize client
     let client = MercadoPagoClient::builder(&access_token).build()?;
 
-    // User ID (Collector ID) from environment
-    let user_id: u64 = env::var("MERCADO_PAGO_USER_ID")
-        .expect("MERCADO_PAGO_USER_ID must be set in .env or environment")
-        .parse()
-        .expect("MERCADO_PAGO_USER_ID must be a valid u64");
+    // User ID (Collector ID) of the account that owns the access token
+    let user_id = client.user_id().await?;
 
     println!("--- Account Discovery ---");
 
@@ -2,11 +2,10 @@ use mercadopago_sdk::MercadoPagoClient;
 use mercadopago_sdk::models::instore::{
     PosRequest, QrOrderItem, QrOrderRequest, StoreLocation, StoreRequest,
 };
-use qrcode::QrCode;
+use mercadopago_sdk::wait::{PaymentOutcome, WaitOptions};
 use rand::Rng;
 use std::env;
 use std::time::Duration;
-use tokio::time::sleep;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -20,11 +19,8 @@ async fn main() -> anyhow::Result<()> {
     // Initialize client
     let client = MercadoPagoClient::builder(&access_token).build()?;
 
-    // User ID (Collector ID) from environment
-    let user_id: u64 = env::var("MERCADO_PAGO_USER_ID")
-        .expect("MERCADO_PAGO_USER_ID must be set in .env or environment")
-        .parse()
-        .expect("MERCADO_PAGO_USER_ID must be a valid u64");
+    // User ID (Collector ID) of the account that owns the access token
+    let user_id = client.user_id().await?;
 
     // Generate random suffix to avoid collisions
     let mut rng = rand::thread_rng();
@@ -101,44 +97,35 @@ async fn main() -> anyhow::Result<()> {
 
     // 4. Render QR Code
     println!("\n--- QR CODE ---");
-    let code = QrCode::new(&response.qr_data)?;
-    let image = code.render().light_color(' ').dark_color('█').build();
-
-    println!("{}", image);
+    println!("{}", response.to_terminal_string()?);
     println!("----------------\n");
     println!("ESCANEA EL CÓDIGO CON TU APP DE MERCADO PAGO PARA PAGAR $150.0");
-    println!("Esperando confirmación de pago (Polling)... Presiona Ctrl+C para cancelar.\n");
-
-    // 5. Wait for payment (Polling loop)
-    loop {
-        match client.search_payments(&external_reference).await {
-            Ok(search_res) => {
-                if let Some(payment) = search_res.results.first() {
-                    println!("\n¡PAGO DETECTADO!");
-                    println!("   ID de Pago: {}", payment.id);
-                    println!("   Estado: {}", payment.status);
-
-                    if payment.status == "approved" {
-                        println!(
-                            "\n✅ ¡VENTA CONFIRMADA! El pago ha sido acreditado exitosamente."
-                        );
-                        println!("Ya puedes entregar el producto al cliente.");
-                        break;
-                    } else if payment.status == "rejected" {
-                        println!("\n❌ PAGO RECHAZADO. Por favor, solicita otro medio de pago.");
-                        break;
-                    } else {
-                        println!(
-                            "   -> El estado es '{}'. Todavía procesando...",
-                            payment.status
-                        );
-                    }
-                }
-            }
-            Err(e) => eprintln!("Error consultando estado: {}", e),
+    println!("Esperando confirmación de pago... Presiona Ctrl+C para cancelar.\n");
+
+    // 5. Wait for payment
+    let options = WaitOptions::new()
+        .timeout(Duration::from_secs(600))
+        .max_interval(Duration::from_secs(3));
+
+    match client
+        .wait_for_payment(&external_reference, options)
+        .await?
+    {
+        PaymentOutcome::Approved(payment) => {
+            println!("\n¡PAGO DETECTADO!");
+            println!("   ID de Pago: {}", payment.id);
+            println!("\n✅ ¡VENTA CONFIRMADA! El pago ha sido acreditado exitosamente.");
+            println!("Ya puedes entregar el producto al cliente.");
+        }
+        PaymentOutcome::Rejected(payment) => {
+            println!("\n¡PAGO DETECTADO!");
+            println!("   ID de Pago: {}", payment.id);
+            println!("   Estado: {}", payment.status);
+            println!("\n❌ PAGO RECHAZADO. Por favor, solicita otro medio de pago.");
+        }
+        PaymentOutcome::TimedOut | PaymentOutcome::Cancelled => {
+            println!("\nNo se recibió el pago a tiempo.");
         }
-
-        sleep(Duration::from_secs(3)).await;
     }
 
     Ok(())
@@ -1,6 +1,5 @@
 use mercadopago_sdk::MercadoPagoClient;
 use mercadopago_sdk::models::instore::{QrOrderItem, QrOrderRequest};
-use qrcode::QrCode;
 use std::env;
 
 #[tokio::main]
@@ -15,11 +14,8 @@ async fn main() -> anyhow::Result<()> {
     // Initialize client
     let client = MercadoPagoClient::builder(&access_token).build()?;
 
-    // User ID (Collector ID) from environment
-    let user_id: u64 = env::var("MERCADO_PAGO_USER_ID")
-        .expect("MERCADO_PAGO_USER_ID must be set in .env or environment")
-        .parse()
-        .expect("MERCADO_PAGO_USER_ID must be a valid u64");
+    // User ID (Collector ID) of the account that owns the access token
+    let user_id = client.user_id().await?;
     // External ID of the POS (Point of Sale). You should have created this in your Mercado Pago account.
     // For this example, we'll use a placeholder.
     let pos_id = "SUCURSAL001";
@@ -53,10 +49,7 @@ async fn main() -> anyhow::Result<()> {
             );
 
             // Generate the QR code from the qr_data
-            let code = QrCode::new(&response.qr_data)?;
-            let image = code.render().light_color(' ').dark_color('█').build();
-
-            println!("{}", image);
+            println!("{}", response.to_terminal_string()?);
             println!(
                 "----------------
 "
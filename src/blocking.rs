@@ -0,0 +1,274 @@
+//! A synchronous client for CLIs and batch scripts.
+//!
+//! Available with the `blocking` feature. [`MercadoPagoClient`] wraps the async
+//! [`crate::MercadoPagoClient`] and drives it on an internal single-threaded
+//! Tokio runtime, so it has the same typed methods, retries, circuit breaker,
+//! rate limiter and [`Error`] type.
+//!
+//! Do not use it from async code: its methods panic when called inside a
+//! Tokio runtime, and dropping the last clone there panics too. Use the async
+//! client instead.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::blocking::MercadoPagoClient;
+//!
+//! let client = MercadoPagoClient::builder("YOUR_ACCESS_TOKEN").build()?;
+//! let payment = client.get_payment(123456789)?;
+//! println!("{:?}", payment.status);
+//! # Ok::<(), mercadopago_sdk::Error>(())
+//! ```
+use crate::client::breaker::{CircuitBreakerConfig, CircuitState, EndpointGroup};
+use crate::client::ratelimit::RateLimiterConfig;
+use crate::client::stats::ClientStats;
+use crate::client::{ApiResponse, MercadoPagoClientBuilder as AsyncBuilder};
+use crate::credentials::CredentialsProvider;
+use crate::error::Error;
+use crate::models::chargebacks::{Chargeback, DocumentationFile};
+use crate::models::common::SearchResponse;
+use crate::models::instore::{
+    PosRequest, PosResponse, QrOrderRequest, QrOrderResponse, StoreRequest, StoreResponse,
+};
+use crate::models::orders::{Order, OrderRequest, OrderSearchResponse, RefundOrderRequest};
+use crate::models::payments::{PaymentRequest, PaymentResponse};
+use crate::models::point::{
+    OperatingMode, OperatingModeResponse, PaymentIntent, PaymentIntentEvents, PaymentIntentRequest,
+    PointDeviceList,
+};
+use crate::models::preferences::{PreferenceRequest, PreferenceResponse};
+use crate::models::refunds::{RefundRequest, RefundResponse};
+use crate::models::users::{TestUser, User};
+use crate::oauth::OAuthSession;
+use crate::wait::{PaymentOutcome, WaitOptions};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Generates blocking wrappers for methods of the async client.
+macro_rules! blocking {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            #[doc = concat!(
+                "Blocking version of [`MercadoPagoClient::", stringify!($name),
+                "`](crate::MercadoPagoClient::", stringify!($name), ")."
+            )]
+            pub fn $name(&self $(, $arg: $ty)*) -> Result<$ret, Error> {
+                self.block_on(self.client.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// A synchronous client for the Mercado Pago API.
+///
+/// Cloning is cheap: clones share the runtime, the connection pool and the
+/// resilience state.
+#[derive(Clone)]
+pub struct MercadoPagoClient {
+    client: crate::MercadoPagoClient,
+    runtime: Arc<Runtime>,
+}
+
+impl MercadoPagoClient {
+    /// Creates a new builder for the client.
+    pub fn builder(access_token: &str) -> MercadoPagoClientBuilder {
+        MercadoPagoClientBuilder {
+            inner: crate::MercadoPagoClient::builder(access_token),
+        }
+    }
+
+    /// Returns the async client this client drives, e.g., to build a request
+    /// for [`block_on`](Self::block_on).
+    pub fn as_async(&self) -> &crate::MercadoPagoClient {
+        &self.client
+    }
+
+    /// Runs `future` to completion on the client's runtime.
+    ///
+    /// An escape hatch for async APIs without a blocking wrapper:
+    ///
+    /// ```no_run
+    /// # use mercadopago_sdk::blocking::MercadoPagoClient;
+    /// use mercadopago_sdk::reports::ReportKind;
+    ///
+    /// # let client = MercadoPagoClient::builder("YOUR_ACCESS_TOKEN").build()?;
+    /// let files = client.block_on(client.as_async().reports(ReportKind::Settlement).list())?;
+    /// # Ok::<(), mercadopago_sdk::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within an async runtime.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Returns a client that authenticates with `provider` and shares this
+    /// client's runtime, connection pool and settings.
+    pub fn with_credentials(&self, provider: Arc<dyn CredentialsProvider>) -> MercadoPagoClient {
+        MercadoPagoClient {
+            client: self.client.with_credentials(provider),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns a snapshot of the requests made by this client and its clones.
+    pub fn stats(&self) -> ClientStats {
+        self.client.stats()
+    }
+
+    /// Returns the circuit breaker state for `group`.
+    pub fn circuit_state(&self, group: EndpointGroup) -> CircuitState {
+        self.client.circuit_state(group)
+    }
+
+    blocking! {
+        fn create_payment(&self, request: PaymentRequest) -> PaymentResponse;
+        fn create_payment_with_response(&self, request: PaymentRequest) -> ApiResponse<PaymentResponse>;
+        fn create_marketplace_payment(&self, request: PaymentRequest, application_fee: f64) -> PaymentResponse;
+        fn get_payment(&self, id: u64) -> PaymentResponse;
+        fn get_payment_with_response(&self, id: u64) -> ApiResponse<PaymentResponse>;
+        fn search_payments(&self, external_reference: &str) -> SearchResponse<PaymentResponse>;
+        fn create_preference(&self, request: PreferenceRequest) -> PreferenceResponse;
+        fn create_preference_with_response(&self, request: PreferenceRequest) -> ApiResponse<PreferenceResponse>;
+        fn create_qr_order(&self, user_id: u64, pos_id: &str, request: QrOrderRequest) -> QrOrderResponse;
+        fn create_order(&self, request: OrderRequest, idempotency_key: &str) -> Order;
+        fn get_order(&self, id: &str) -> Order;
+        fn process_order(&self, id: &str, idempotency_key: &str) -> Order;
+        fn cancel_order(&self, id: &str, idempotency_key: &str) -> Order;
+        fn refund_order(&self, id: &str, request: Option<RefundOrderRequest>, idempotency_key: &str) -> Order;
+        fn get_me(&self) -> User;
+        fn user_id(&self) -> u64;
+        fn create_test_user(&self, site_id: &str, description: &str) -> TestUser;
+        fn create_store(&self, user_id: u64, request: StoreRequest) -> StoreResponse;
+        fn create_pos(&self, request: PosRequest) -> PosResponse;
+        fn search_stores(&self, user_id: u64) -> SearchResponse<StoreResponse>;
+        fn list_pos(&self) -> SearchResponse<PosResponse>;
+        fn list_point_devices(&self, store_id: Option<&str>, pos_id: Option<u64>) -> PointDeviceList;
+        fn change_device_operating_mode(&self, device_id: &str, operating_mode: OperatingMode) -> OperatingModeResponse;
+        fn create_payment_intent(&self, device_id: &str, request: PaymentIntentRequest) -> PaymentIntent;
+        fn get_payment_intent(&self, id: &str) -> PaymentIntent;
+        fn cancel_payment_intent(&self, device_id: &str, id: &str) -> ();
+        fn get_payment_intent_events(&self, start_date: &str, end_date: &str) -> PaymentIntentEvents;
+        fn create_refund(&self, payment_id: u64, request: RefundRequest) -> RefundResponse;
+        fn list_refunds(&self, payment_id: u64) -> Vec<RefundResponse>;
+        fn get_chargeback(&self, id: &str) -> Chargeback;
+        fn search_chargebacks(&self, payment_id: u64) -> SearchResponse<Chargeback>;
+        fn upload_chargeback_documentation(&self, id: &str, files: &[DocumentationFile]) -> ();
+        fn wait_for_payment(&self, external_reference: &str, options: WaitOptions) -> PaymentOutcome;
+        fn wait_for_payment_id(&self, payment_id: u64, options: WaitOptions) -> PaymentOutcome;
+    }
+
+    /// Blocking version of [`MercadoPagoClient::search_payments_generic`](crate::MercadoPagoClient::search_payments_generic).
+    pub fn search_payments_generic<T: serde::Serialize + ?Sized>(
+        &self,
+        filters: &T,
+    ) -> Result<SearchResponse<PaymentResponse>, Error> {
+        self.block_on(self.client.search_payments_generic(filters))
+    }
+
+    /// Blocking version of [`MercadoPagoClient::search_orders`](crate::MercadoPagoClient::search_orders).
+    pub fn search_orders<T: serde::Serialize + ?Sized>(
+        &self,
+        filters: &T,
+    ) -> Result<OrderSearchResponse, Error> {
+        self.block_on(self.client.search_orders(filters))
+    }
+}
+
+impl std::fmt::Debug for MercadoPagoClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MercadoPagoClient").finish_non_exhaustive()
+    }
+}
+
+fn new_runtime() -> Result<Runtime, Error> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| {
+            Error::Internal(format!(
+                "Failed to start the blocking client runtime: {}",
+                e
+            ))
+        })
+}
+
+/// A builder for the blocking [`MercadoPagoClient`], with the same options as
+/// the async [`MercadoPagoClientBuilder`](crate::client::MercadoPagoClientBuilder).
+pub struct MercadoPagoClientBuilder {
+    inner: AsyncBuilder,
+}
+
+impl MercadoPagoClientBuilder {
+    /// Sets the base URL for the API. Defaults to `https://api.mercadopago.com`.
+    pub fn base_url(self, url: &str) -> Self {
+        self.map(|b| b.base_url(url))
+    }
+
+    /// Sets the total timeout for requests.
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.map(|b| b.timeout(timeout))
+    }
+
+    /// Sets the connection timeout for requests.
+    pub fn connect_timeout(self, timeout: Duration) -> Self {
+        self.map(|b| b.connect_timeout(timeout))
+    }
+
+    /// Sets the maximum number of retries for rate-limited (429) requests. Defaults to 3.
+    pub fn max_retries(self, max_retries: u32) -> Self {
+        self.map(|b| b.max_retries(max_retries))
+    }
+
+    /// Caps how long a `429` retry waits. Defaults to 60 seconds.
+    pub fn max_retry_wait(self, max_wait: Duration) -> Self {
+        self.map(|b| b.max_retry_wait(max_wait))
+    }
+
+    /// Enables the client-side rate limiter. Disabled by default.
+    pub fn rate_limiter(self, config: RateLimiterConfig) -> Self {
+        self.map(|b| b.rate_limiter(config))
+    }
+
+    /// Enables the circuit breaker. Disabled by default.
+    pub fn circuit_breaker(self, config: CircuitBreakerConfig) -> Self {
+        self.map(|b| b.circuit_breaker(config))
+    }
+
+    /// Obtains access tokens from `provider` instead of the static access token.
+    pub fn credentials<P: CredentialsProvider + 'static>(self, provider: P) -> Self {
+        self.map(|b| b.credentials(provider))
+    }
+
+    /// Authenticates with a seller's OAuth session instead of the static access token.
+    pub fn oauth_session(self, session: OAuthSession) -> Self {
+        self.map(|b| b.oauth_session(session))
+    }
+
+    /// Records the client's requests to `cassette`, or replays them from it.
+    #[cfg(feature = "testing")]
+    pub fn cassette(self, cassette: crate::testing::cassette::Cassette) -> Self {
+        self.map(|b| b.cassette(cassette))
+    }
+
+    /// Builds the client and starts its runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client or the runtime fails to initialize.
+    pub fn build(self) -> Result<MercadoPagoClient, Error> {
+        Ok(MercadoPagoClient {
+            client: self.inner.build()?,
+            runtime: Arc::new(new_runtime()?),
+        })
+    }
+
+    fn map(self, f: impl FnOnce(AsyncBuilder) -> AsyncBuilder) -> Self {
+        Self {
+            inner: f(self.inner),
+        }
+    }
+}
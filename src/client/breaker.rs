@@ -0,0 +1,220 @@
+//! Circuit breaker that fails fast while an endpoint group is unhealthy.
+//!
+//! Enable it with [`MercadoPagoClientBuilder::circuit_breaker`](super::MercadoPagoClientBuilder::circuit_breaker).
+//! Each [`EndpointGroup`] has its own circuit: after
+//! [`failure_threshold`](CircuitBreakerConfig::failure_threshold) consecutive
+//! network errors, timeouts or `5xx` responses it opens, and requests to that
+//! group return [`Error::CircuitOpen`](crate::Error::CircuitOpen) without
+//! touching the network. Once the [`cooldown`](CircuitBreakerConfig::cooldown)
+//! elapses, a single probe request is let through (half-open): a non-`5xx`
+//! response closes the circuit, a failure opens it for another cooldown. A
+//! probe that ends without a response for another reason (e.g., the
+//! credentials provider fails) leaves the circuit half-open for the next
+//! request to probe.
+
+use super::route::url_path;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A family of endpoints sharing one circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EndpointGroup {
+    /// Payments, refunds and chargebacks (`/v1/payments`, `/v1/chargebacks`).
+    Payments,
+    /// Checkout Pro preferences (`/checkout/preferences`).
+    Preferences,
+    /// Stores, POS, QR orders and Point terminals.
+    Instore,
+    /// The Orders API (`/v1/orders`).
+    Orders,
+    /// Any other endpoint.
+    Other,
+}
+
+impl EndpointGroup {
+    /// Returns the group a request path (or full URL) belongs to.
+    pub fn for_path(path: &str) -> Self {
+        let path = url_path(path);
+
+        if path.starts_with("/v1/payments") || path.starts_with("/v1/chargebacks") {
+            EndpointGroup::Payments
+        } else if path.starts_with("/checkout/preferences") {
+            EndpointGroup::Preferences
+        } else if path.starts_with("/v1/orders") {
+            EndpointGroup::Orders
+        } else if path.starts_with("/instore")
+            || path.starts_with("/pos")
+            || path.starts_with("/point")
+            || (path.starts_with("/users/") && path.contains("/stores"))
+        {
+            EndpointGroup::Instore
+        } else {
+            EndpointGroup::Other
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            EndpointGroup::Payments => "payments",
+            EndpointGroup::Preferences => "preferences",
+            EndpointGroup::Instore => "instore",
+            EndpointGroup::Orders => "orders",
+            EndpointGroup::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for EndpointGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// State of the circuit for an [`EndpointGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Requests fail fast until the cooldown elapses.
+    Open,
+    /// The cooldown elapsed; the next request probes the API.
+    HalfOpen,
+}
+
+/// Configuration for the circuit breaker.
+///
+/// ```
+/// use mercadopago_sdk::client::breaker::CircuitBreakerConfig;
+/// use std::time::Duration;
+///
+/// let config = CircuitBreakerConfig::new()
+///     .failure_threshold(3)
+///     .cooldown(Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    failure_threshold: u32,
+    cooldown: Duration,
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a configuration that opens after 5 consecutive failures and
+    /// probes again after 30 seconds.
+    pub fn new() -> Self {
+        Self {
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    /// Number of consecutive failures that opens the circuit. Minimum 1.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// How long the circuit stays open before a probe request is allowed.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Default)]
+struct Circuit {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_started: Option<Instant>,
+}
+
+/// The breaker shared by a client and its clones.
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Mutex<HashMap<EndpointGroup, Circuit>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Admits a request to `group`, or returns how long until the next probe.
+    pub(crate) fn acquire(&self, group: EndpointGroup) -> Result<(), Duration> {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = circuits.entry(group).or_default();
+        let Some(opened_at) = circuit.opened_at else {
+            return Ok(());
+        };
+
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.config.cooldown {
+            return Err(self.config.cooldown - elapsed);
+        }
+        // Only one probe at a time. A probe that never reports back (e.g.,
+        // its future was dropped) is superseded after another cooldown.
+        if let Some(started) = circuit.probe_started {
+            let elapsed = started.elapsed();
+            if elapsed < self.config.cooldown {
+                return Err(self.config.cooldown - elapsed);
+            }
+        }
+        circuit.probe_started = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Records the outcome of a request admitted by [`acquire`](Self::acquire).
+    pub(crate) fn record(&self, group: EndpointGroup, success: bool) {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = circuits.entry(group).or_default();
+
+        if success {
+            *circuit = Circuit::default();
+            return;
+        }
+
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+        if circuit.probe_started.is_some()
+            || circuit.consecutive_failures >= self.config.failure_threshold
+        {
+            #[cfg(feature = "logging")]
+            tracing::warn!(
+                "Circuit for {} endpoints opened after {} consecutive failures",
+                group,
+                circuit.consecutive_failures
+            );
+
+            circuit.opened_at = Some(Instant::now());
+            circuit.probe_started = None;
+        }
+    }
+
+    /// Releases a probe that ended without a response, so the next request can
+    /// probe instead. The circuit stays as it is.
+    pub(crate) fn release(&self, group: EndpointGroup) {
+        let mut circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(circuit) = circuits.get_mut(&group) {
+            circuit.probe_started = None;
+        }
+    }
+
+    pub(crate) fn state(&self, group: EndpointGroup) -> CircuitState {
+        let circuits = self.circuits.lock().unwrap_or_else(|e| e.into_inner());
+        match circuits.get(&group).and_then(|c| c.opened_at) {
+            None => CircuitState::Closed,
+            Some(opened_at) if opened_at.elapsed() < self.config.cooldown => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+}
@@ -1,21 +1,98 @@
+use crate::credentials::{CredentialsProvider, ExposeSecret, StaticCredentials};
 use crate::error::Error;
+use crate::models::chargebacks::{Chargeback, DocumentationFile};
 use crate::models::common::SearchResponse;
 use crate::models::instore::{
     PosRequest, PosResponse, QrOrderRequest, QrOrderResponse, StoreRequest, StoreResponse,
 };
+use crate::models::orders::{Order, OrderRequest, OrderSearchResponse, RefundOrderRequest};
 use crate::models::payments::{PaymentRequest, PaymentResponse};
+use crate::models::point::{
+    OperatingMode, OperatingModeRequest, OperatingModeResponse, PaymentIntent, PaymentIntentEvents,
+    PaymentIntentRequest, PointDeviceList,
+};
 use crate::models::preferences::{PreferenceRequest, PreferenceResponse};
 use crate::models::refunds::{RefundRequest, RefundResponse};
+use crate::models::users::{TestUser, TestUserRequest, User};
+use crate::oauth::OAuthSession;
 use reqwest::{Client, Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::sync::OnceCell;
 use tokio::time::sleep;
 
+pub mod breaker;
+mod multipart;
+pub mod pool;
+pub mod ratelimit;
+mod response;
+mod route;
+pub mod stats;
+#[cfg(feature = "logging")]
+mod telemetry;
+
+use breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState, EndpointGroup};
+use multipart::MultipartForm;
+use ratelimit::{RateLimiter, RateLimiterConfig};
+pub use response::ApiResponse;
+use stats::{ClientStats, Rejection, RetryReason, StatsRecorder};
+
+#[cfg(feature = "testing")]
+use crate::testing::cassette::Cassette;
+
+const IDEMPOTENCY_KEY: &str = "X-Idempotency-Key";
+
+/// Returns a random idempotency key (a UUID v4) for operations that require
+/// one, such as [`MercadoPagoClient::create_order`].
+///
+/// # Errors
+///
+/// Returns [`Error::Internal`] if the operating system random number
+/// generator is unavailable.
+pub fn new_idempotency_key() -> Result<String, Error> {
+    let mut bytes = random_bytes::<16>()?;
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+pub(crate) fn random_bytes<const N: usize>() -> Result<[u8; N], Error> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes)
+        .map_err(|e| Error::Internal(format!("OS random number generator unavailable: {}", e)))?;
+    Ok(bytes)
+}
+
 struct MercadoPagoClientInner {
     client: Client,
-    access_token: String,
+    credentials: Arc<dyn CredentialsProvider>,
     base_url: String,
     max_retries: u32,
+    user_id: OnceCell<u64>,
+    breaker: Option<Arc<CircuitBreaker>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    max_retry_wait: Duration,
+    stats: Arc<StatsRecorder>,
+    #[cfg(feature = "testing")]
+    cassette: Option<Cassette>,
+}
+
+/// What a request targets, used for resilience policies and telemetry.
+struct Operation {
+    method: Method,
+    route: String,
+    group: EndpointGroup,
+    /// Set by [`MercadoPagoRequestBuilder::idempotency_key`].
+    idempotent: bool,
 }
 
 /// The main client for interacting with the Mercado Pago API.
@@ -42,6 +119,43 @@ impl MercadoPagoClient {
         MercadoPagoClientBuilder::new(access_token)
     }
 
+    /// Returns a client that shares this client's connection pool and
+    /// configuration but authenticates with `provider`.
+    pub fn with_credentials(&self, provider: Arc<dyn CredentialsProvider>) -> MercadoPagoClient {
+        MercadoPagoClient {
+            inner: Arc::new(MercadoPagoClientInner {
+                client: self.inner.client.clone(),
+                credentials: provider,
+                base_url: self.inner.base_url.clone(),
+                max_retries: self.inner.max_retries,
+                user_id: OnceCell::new(),
+                breaker: self.inner.breaker.clone(),
+                rate_limiter: self.inner.rate_limiter.clone(),
+                max_retry_wait: self.inner.max_retry_wait,
+                stats: self.inner.stats.clone(),
+                #[cfg(feature = "testing")]
+                cassette: self.inner.cassette.clone(),
+            }),
+        }
+    }
+
+    /// Returns a snapshot of the requests made by this client and its clones.
+    ///
+    /// See [`stats`] for the equivalent metrics exported with the `metrics`
+    /// feature.
+    pub fn stats(&self) -> ClientStats {
+        self.inner.stats.snapshot()
+    }
+
+    /// Returns the circuit breaker state for `group`, e.g., for a health
+    /// check. Always [`CircuitState::Closed`] if no breaker is configured.
+    pub fn circuit_state(&self, group: EndpointGroup) -> CircuitState {
+        self.inner
+            .breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, |breaker| breaker.state(group))
+    }
+
     /// Performs a GET request on the specified path.
     pub fn get(&self, path: &str) -> MercadoPagoRequestBuilder {
         self.request(Method::GET, path)
@@ -75,15 +189,17 @@ impl MercadoPagoClient {
             format!("{}{}", self.inner.base_url, path)
         };
 
-        let rb = self
-            .inner
-            .client
-            .request(method, &url)
-            .bearer_auth(&self.inner.access_token);
+        let rb = self.inner.client.request(method.clone(), &url);
 
         MercadoPagoRequestBuilder {
             builder: rb,
             client: self.clone(),
+            operation: Operation {
+                method,
+                route: route::route_template(path),
+                group: EndpointGroup::for_path(path),
+                idempotent: false,
+            },
         }
     }
 
@@ -93,13 +209,48 @@ impl MercadoPagoClient {
     ///
     /// Returns an error if the request fails or if the API returns an error response.
     pub async fn create_payment(&self, request: PaymentRequest) -> Result<PaymentResponse, Error> {
-        Ok(self
-            .post("/v1/payments")
-            .json(&request)
-            .send()
-            .await?
-            .json()
-            .await?)
+        Ok(self.create_payment_with_response(request).await?.data)
+    }
+
+    /// Like [`create_payment`](Self::create_payment), but also returns the
+    /// response status, headers and raw JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn create_payment_with_response(
+        &self,
+        request: PaymentRequest,
+    ) -> Result<ApiResponse<PaymentResponse>, Error> {
+        self.post("/v1/payments").json(&request).send_json().await
+    }
+
+    /// Creates a marketplace payment on behalf of a seller, collecting
+    /// `application_fee` for the platform.
+    ///
+    /// The client must authenticate with the seller's access token (e.g., one
+    /// obtained from [`ClientPool::for_collector`](pool::ClientPool::for_collector)
+    /// or an [`OAuthSession`]); the payment is
+    /// credited to the seller and the fee to the platform that owns the
+    /// application.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if the fee is negative or not lower than the
+    /// transaction amount, or an error if the request fails.
+    pub async fn create_marketplace_payment(
+        &self,
+        request: PaymentRequest,
+        application_fee: f64,
+    ) -> Result<PaymentResponse, Error> {
+        if !(0.0..request.transaction_amount).contains(&application_fee) {
+            return Err(Error::Internal(format!(
+                "Application fee {} must be between 0 and the transaction amount {}",
+                application_fee, request.transaction_amount
+            )));
+        }
+        self.create_payment(request.with_application_fee(application_fee))
+            .await
     }
 
     /// Returns information about a payment by its ID.
@@ -108,12 +259,20 @@ impl MercadoPagoClient {
     ///
     /// Returns an error if the payment is not found or if the request fails.
     pub async fn get_payment(&self, id: u64) -> Result<PaymentResponse, Error> {
-        Ok(self
-            .get(&format!("/v1/payments/{}", id))
-            .send()
-            .await?
-            .json()
-            .await?)
+        Ok(self.get_payment_with_response(id).await?.data)
+    }
+
+    /// Like [`get_payment`](Self::get_payment), but also returns the response
+    /// status, headers and raw JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payment is not found or if the request fails.
+    pub async fn get_payment_with_response(
+        &self,
+        id: u64,
+    ) -> Result<ApiResponse<PaymentResponse>, Error> {
+        self.get(&format!("/v1/payments/{}", id)).send_json().await
     }
 
     /// Searches for payments based on an external reference.
@@ -128,10 +287,9 @@ impl MercadoPagoClient {
         Ok(self
             .get("/v1/payments/search")
             .query(&[("external_reference", external_reference)])
-            .send()
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
     }
 
     /// Generic search for payments with any criteria.
@@ -146,10 +304,9 @@ impl MercadoPagoClient {
         Ok(self
             .get("/v1/payments/search")
             .query(filters)
-            .send()
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
     }
 
     /// Creates a new payment preference for Checkout Pro.
@@ -161,13 +318,23 @@ impl MercadoPagoClient {
         &self,
         request: PreferenceRequest,
     ) -> Result<PreferenceResponse, Error> {
-        Ok(self
-            .post("/checkout/preferences")
+        Ok(self.create_preference_with_response(request).await?.data)
+    }
+
+    /// Like [`create_preference`](Self::create_preference), but also returns
+    /// the response status, headers and raw JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn create_preference_with_response(
+        &self,
+        request: PreferenceRequest,
+    ) -> Result<ApiResponse<PreferenceResponse>, Error> {
+        self.post("/checkout/preferences")
             .json(&request)
-            .send()
-            .await?
-            .json()
-            .await?)
+            .send_json()
+            .await
     }
 
     /// Creates a dynamic QR order for a specific collector and POS.
@@ -187,10 +354,167 @@ impl MercadoPagoClient {
                 user_id, pos_id
             ))
             .json(&request)
-            .send()
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Creates an order in the Orders API.
+    ///
+    /// `idempotency_key` identifies the operation: retrying with the same key
+    /// (e.g., after a timeout) returns the original order instead of charging
+    /// twice. Generate one per order with [`new_idempotency_key`] and store it
+    /// with the order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `idempotency_key` is empty, or an error
+    /// if the request fails or if the API returns an error response.
+    pub async fn create_order(
+        &self,
+        request: OrderRequest,
+        idempotency_key: &str,
+    ) -> Result<Order, Error> {
+        Ok(self
+            .post("/v1/orders")
+            .idempotency_key(idempotency_key)?
+            .json(&request)
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Returns an order by its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the order is not found or if the request fails.
+    pub async fn get_order(&self, id: &str) -> Result<Order, Error> {
+        Ok(self
+            .get(&format!("/v1/orders/{}", id))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Processes an order created with [`ProcessingMode::Manual`](crate::models::orders::ProcessingMode::Manual).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `idempotency_key` is empty, or an error
+    /// if the request fails or if the API returns an error response.
+    pub async fn process_order(&self, id: &str, idempotency_key: &str) -> Result<Order, Error> {
+        Ok(self
+            .post(&format!("/v1/orders/{}/process", id))
+            .idempotency_key(idempotency_key)?
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Cancels an order that has not been processed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `idempotency_key` is empty, or an error
+    /// if the request fails or if the API returns an error response.
+    pub async fn cancel_order(&self, id: &str, idempotency_key: &str) -> Result<Order, Error> {
+        Ok(self
+            .post(&format!("/v1/orders/{}/cancel", id))
+            .idempotency_key(idempotency_key)?
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Refunds an order. Passing `None` refunds it in full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `idempotency_key` is empty, or an error
+    /// if the request fails or if the API returns an error response.
+    pub async fn refund_order(
+        &self,
+        id: &str,
+        request: Option<RefundOrderRequest>,
+        idempotency_key: &str,
+    ) -> Result<Order, Error> {
+        let mut rb = self
+            .post(&format!("/v1/orders/{}/refund", id))
+            .idempotency_key(idempotency_key)?;
+        if let Some(request) = request {
+            rb = rb.json(&request);
+        }
+        Ok(rb.send_json().await?.data)
+    }
+
+    /// Searches orders with any criteria (e.g., `external_reference`,
+    /// `begin_date`, `end_date`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn search_orders<T: serde::Serialize + ?Sized>(
+        &self,
+        filters: &T,
+    ) -> Result<OrderSearchResponse, Error> {
+        Ok(self
+            .get("/v1/orders")
+            .query(filters)
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Returns the user that owns the access token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn get_me(&self) -> Result<User, Error> {
+        Ok(self.get("/users/me").send_json().await?.data)
+    }
+
+    /// Returns the ID of the user that owns the access token, as needed by
+    /// [`create_store`](Self::create_store) and
+    /// [`create_qr_order`](Self::create_qr_order).
+    ///
+    /// The ID is fetched with [`get_me`](Self::get_me) on the first call and
+    /// cached for the lifetime of the client and its clones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn user_id(&self) -> Result<u64, Error> {
+        self.inner
+            .user_id
+            .get_or_try_init(|| async { Ok(self.get_me().await?.id) })
+            .await
+            .copied()
+    }
+
+    /// Creates a test user for sandbox integrations.
+    ///
+    /// Create one test seller and one test buyer per site; the API limits the
+    /// number of test users per account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn create_test_user(
+        &self,
+        site_id: &str,
+        description: &str,
+    ) -> Result<TestUser, Error> {
+        Ok(self
+            .post("/users/test_user")
+            .json(&TestUserRequest {
+                site_id: site_id.to_string(),
+                description: Some(description.to_string()),
+            })
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
     }
 
     /// Creates a new Store for a user.
@@ -206,10 +530,9 @@ impl MercadoPagoClient {
         Ok(self
             .post(&format!("/users/{}/stores", user_id))
             .json(&request)
-            .send()
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
     }
 
     /// Creates a new Point of Sale (POS).
@@ -218,13 +541,7 @@ impl MercadoPagoClient {
     ///
     /// Returns an error if the request fails or if the API returns an error response.
     pub async fn create_pos(&self, request: PosRequest) -> Result<PosResponse, Error> {
-        Ok(self
-            .post("/pos")
-            .json(&request)
-            .send()
-            .await?
-            .json()
-            .await?)
+        Ok(self.post("/pos").json(&request).send_json().await?.data)
     }
 
     /// Searches for stores belonging to a user.
@@ -238,10 +555,123 @@ impl MercadoPagoClient {
     ) -> Result<SearchResponse<StoreResponse>, Error> {
         Ok(self
             .get(&format!("/users/{}/stores/search", user_id))
-            .send()
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Lists the Point terminals, optionally filtered by store and POS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn list_point_devices(
+        &self,
+        store_id: Option<&str>,
+        pos_id: Option<u64>,
+    ) -> Result<PointDeviceList, Error> {
+        let mut query = Vec::new();
+        if let Some(store_id) = store_id {
+            query.push(("store_id", store_id.to_string()));
+        }
+        if let Some(pos_id) = pos_id {
+            query.push(("pos_id", pos_id.to_string()));
+        }
+        Ok(self
+            .get("/point/integration-api/devices")
+            .query(&query)
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Changes the operating mode of a Point terminal.
+    ///
+    /// Devices must be in [`OperatingMode::Pdv`] to receive payment intents.
+    /// The terminal applies the change after a restart.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn change_device_operating_mode(
+        &self,
+        device_id: &str,
+        operating_mode: OperatingMode,
+    ) -> Result<OperatingModeResponse, Error> {
+        Ok(self
+            .patch(&format!("/point/integration-api/devices/{}", device_id))
+            .json(&OperatingModeRequest { operating_mode })
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Sends a charge to a Point terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the device is busy or not in PDV mode, or if the request fails.
+    pub async fn create_payment_intent(
+        &self,
+        device_id: &str,
+        request: PaymentIntentRequest,
+    ) -> Result<PaymentIntent, Error> {
+        Ok(self
+            .post(&format!(
+                "/point/integration-api/devices/{}/payment-intents",
+                device_id
+            ))
+            .json(&request)
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Returns a payment intent by its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the intent is not found or if the request fails.
+    pub async fn get_payment_intent(&self, id: &str) -> Result<PaymentIntent, Error> {
+        Ok(self
+            .get(&format!("/point/integration-api/payment-intents/{}", id))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Cancels a payment intent that the terminal has not processed yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the intent cannot be cancelled or if the request fails.
+    pub async fn cancel_payment_intent(&self, device_id: &str, id: &str) -> Result<(), Error> {
+        self.delete(&format!(
+            "/point/integration-api/devices/{}/payment-intents/{}",
+            device_id, id
+        ))
+        .send()
+        .await?;
+        Ok(())
+    }
+
+    /// Returns the state changes of payment intents between two dates
+    /// (`YYYY-MM-DD`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn get_payment_intent_events(
+        &self,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<PaymentIntentEvents, Error> {
+        Ok(self
+            .get("/point/integration-api/payment-intents/events")
+            .query(&[("startDate", start_date), ("endDate", end_date)])
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
     }
 
     /// Lists all Points of Sale (POS).
@@ -250,7 +680,7 @@ impl MercadoPagoClient {
     ///
     /// Returns an error if the request fails or if the API returns an error response.
     pub async fn list_pos(&self) -> Result<SearchResponse<PosResponse>, Error> {
-        Ok(self.get("/pos").send().await?.json().await?)
+        Ok(self.get("/pos").send_json().await?.data)
     }
 
     /// Creates a refund for a payment.
@@ -266,58 +696,251 @@ impl MercadoPagoClient {
         Ok(self
             .post(&format!("/v1/payments/{}/refunds", payment_id))
             .json(&request)
-            .send()
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Lists the refunds of a payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn list_refunds(&self, payment_id: u64) -> Result<Vec<RefundResponse>, Error> {
+        Ok(self
+            .get(&format!("/v1/payments/{}/refunds", payment_id))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Returns a chargeback by its ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chargeback is not found or if the request fails.
+    pub async fn get_chargeback(&self, id: &str) -> Result<Chargeback, Error> {
+        Ok(self
+            .get(&format!("/v1/chargebacks/{}", id))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Searches the chargebacks of a payment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn search_chargebacks(
+        &self,
+        payment_id: u64,
+    ) -> Result<SearchResponse<Chargeback>, Error> {
+        Ok(self
+            .get("/v1/chargebacks/search")
+            .query(&[("payment_id", payment_id)])
+            .send_json()
             .await?
-            .json()
-            .await?)
+            .data)
+    }
+
+    /// Uploads documentation (invoices, delivery receipts, etc.) to dispute a
+    /// chargeback.
+    ///
+    /// Only possible while [`Chargeback::documentation_required`] is `true`
+    /// and before [`Chargeback::date_documentation_deadline`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `files` is empty or no random multipart
+    /// boundary can be generated, or an error if the request fails or if the API returns an error response.
+    pub async fn upload_chargeback_documentation(
+        &self,
+        id: &str,
+        files: &[DocumentationFile],
+    ) -> Result<(), Error> {
+        if files.is_empty() {
+            return Err(Error::Internal("At least one file is required".to_string()));
+        }
+        let form = files.iter().fold(MultipartForm::new()?, |form, file| {
+            form.file(
+                "files[]",
+                &file.file_name,
+                &file.content_type,
+                &file.content,
+            )
+        });
+        let (content_type, body) = form.finish();
+
+        self.post(&format!("/v1/chargebacks/{}/documentation", id))
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    /// Sends `rb` through the circuit breaker and the retry loop, turning
+    /// non-2xx responses into errors.
+    async fn send_operation(
+        &self,
+        rb: RequestBuilder,
+        operation: &Operation,
+    ) -> Result<Response, Error> {
+        let group = operation.group;
+        let breaker = self.inner.breaker.as_deref();
+        if let Some(breaker) = breaker
+            && let Err(retry_after) = breaker.acquire(group)
+        {
+            self.inner
+                .stats
+                .record_rejection(group, Rejection::CircuitOpen);
+            return Err(Error::CircuitOpen { group, retry_after });
+        }
+
+        let res = self.execute_with_retry(rb, operation).await;
+
+        if let Some(breaker) = breaker {
+            match &res {
+                Ok(res) => breaker.record(group, !res.status().is_server_error()),
+                Err(Error::Network(_) | Error::Timeout(_)) => breaker.record(group, false),
+                // No response, so no evidence about the API's health.
+                Err(_) => breaker.release(group),
+            }
+        }
+
+        let res = res?;
+
+        if res.status().is_success() {
+            Ok(res)
+        } else {
+            let status = res.status();
+            let headers = res.headers().clone();
+            let body = res.text().await?;
+            Err(Error::from_response(status, &headers, body))
+        }
+    }
+
+    /// Sends a request, through the cassette if one is configured.
+    async fn dispatch(&self, rb: RequestBuilder) -> Result<Response, Error> {
+        #[cfg(feature = "testing")]
+        if let Some(cassette) = &self.inner.cassette {
+            let (client, request) = rb.build_split();
+            return cassette.dispatch(&client, request?).await;
+        }
+        Ok(rb.send().await?)
     }
 
-    async fn execute_with_retry(&self, rb: RequestBuilder) -> Result<Response, Error> {
+    async fn execute_with_retry(
+        &self,
+        rb: RequestBuilder,
+        operation: &Operation,
+    ) -> Result<Response, Error> {
+        let group = operation.group;
+        let stats = &self.inner.stats;
         let max_retries = self.inner.max_retries;
+        let limiter = self.inner.rate_limiter.as_deref();
         let mut attempts = 0;
+        let mut refreshed = false;
+        #[cfg(feature = "logging")]
+        let mut resends = 0;
 
         loop {
+            #[cfg(feature = "logging")]
+            {
+                if resends > 0 {
+                    telemetry::record_resend(resends);
+                }
+                resends += 1;
+            }
+
+            if let Some(limiter) = limiter
+                && limiter.acquire(group).await
+            {
+                stats.record_rejection(group, Rejection::RateLimited);
+            }
+
+            let access_token = self.inner.credentials.token().await?;
             let rb_clone = rb
                 .try_clone()
-                .ok_or_else(|| Error::Internal("Cannot clone request for retry".to_string()))?;
+                .ok_or_else(|| Error::Internal("Cannot clone request for retry".to_string()))?
+                .bearer_auth(access_token.expose_secret());
+            #[cfg(feature = "otel")]
+            let rb_clone = telemetry::inject_trace_context(rb_clone);
 
-            #[cfg(feature = "logging")]
-            tracing::info!("Sending request to Mercado Pago");
+            let res = self.dispatch(rb_clone).await?;
+
+            if let Some(limiter) = limiter {
+                limiter.observe(group, res.headers(), self.inner.max_retry_wait);
+            }
+
+            if res.status() == 401 && !refreshed {
+                refreshed = true;
+                // A failed refresh must not hide the 401 and its request id.
+                let renewed = self
+                    .inner
+                    .credentials
+                    .refresh(&access_token)
+                    .await
+                    .inspect_err(|_e| {
+                        #[cfg(feature = "logging")]
+                        tracing::warn!(error = %_e, "Credentials refresh after 401 failed");
+                    })
+                    .unwrap_or(false);
+                if renewed {
+                    #[cfg(feature = "logging")]
+                    tracing::warn!(
+                        "Received 401 Unauthorized, retrying with refreshed credentials"
+                    );
+
+                    stats.record_retry(
+                        &operation.method,
+                        &operation.route,
+                        RetryReason::Unauthorized,
+                    );
 
-            let res = rb_clone.send().await?;
+                    continue;
+                }
+            }
 
             if res.status() == 429 {
                 attempts += 1;
                 if attempts > max_retries {
-                    return Err(Error::ApiError {
-                        message: "Too many retries".to_string(),
-                        error: "too_many_requests".to_string(),
-                        status: 429,
-                        cause: None,
-                    });
+                    // Surface the last 429 so the caller gets its body and headers.
+                    return Ok(res);
                 }
 
                 let retry_after = res
                     .headers()
                     .get("Retry-After")
                     .and_then(|h| h.to_str().ok())
-                    .and_then(|s| s.parse::<u64>().ok())
-                    .unwrap_or(1);
+                    .and_then(ratelimit::parse_retry_after)
+                    .unwrap_or(Duration::from_secs(1))
+                    .min(self.inner.max_retry_wait);
 
                 #[cfg(feature = "logging")]
                 tracing::warn!(
-                    "Received 429 Too Many Requests, retrying after {}s (attempt {}/{})",
+                    "Received 429 Too Many Requests, retrying after {:?} (attempt {}/{})",
                     retry_after,
                     attempts,
                     max_retries
                 );
 
-                sleep(Duration::from_secs(retry_after)).await;
+                // Hold back concurrent requests to the same group as well.
+                if let Some(limiter) = limiter {
+                    limiter.pause(group, tokio::time::Instant::now() + retry_after);
+                }
+                stats.record_retry(
+                    &operation.method,
+                    &operation.route,
+                    RetryReason::RateLimited,
+                );
+                sleep(retry_after).await;
                 continue;
             }
 
             #[cfg(feature = "logging")]
-            tracing::info!("Received response with status: {}", res.status());
+            tracing::debug!(status = res.status().as_u16(), "Received response");
 
             return Ok(res);
         }
@@ -326,21 +949,31 @@ impl MercadoPagoClient {
 
 /// A builder for [`MercadoPagoClient`].
 pub struct MercadoPagoClientBuilder {
-    access_token: String,
+    credentials: Arc<dyn CredentialsProvider>,
     base_url: String,
     timeout: Duration,
     connect_timeout: Duration,
     max_retries: u32,
+    max_retry_wait: Duration,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    rate_limiter: Option<RateLimiterConfig>,
+    #[cfg(feature = "testing")]
+    cassette: Option<Cassette>,
 }
 
 impl MercadoPagoClientBuilder {
     fn new(access_token: &str) -> Self {
         Self {
-            access_token: access_token.to_string(),
+            credentials: Arc::new(StaticCredentials::new(access_token)),
             base_url: "https://api.mercadopago.com".to_string(),
             timeout: Duration::from_secs(30),
             connect_timeout: Duration::from_secs(10),
             max_retries: 3,
+            max_retry_wait: Duration::from_secs(60),
+            circuit_breaker: None,
+            rate_limiter: None,
+            #[cfg(feature = "testing")]
+            cassette: None,
         }
     }
 
@@ -368,12 +1001,70 @@ impl MercadoPagoClientBuilder {
         self
     }
 
+    /// Caps how long a `429` retry waits, whatever `Retry-After` asks for.
+    /// Defaults to 60 seconds.
+    pub fn max_retry_wait(mut self, max_wait: Duration) -> Self {
+        self.max_retry_wait = max_wait;
+        self
+    }
+
+    /// Enables a client-side token-bucket rate limiter per [`EndpointGroup`],
+    /// shared by all clones of the client. Disabled by default.
+    ///
+    /// See [`ratelimit`] for details.
+    pub fn rate_limiter(mut self, config: RateLimiterConfig) -> Self {
+        self.rate_limiter = Some(config);
+        self
+    }
+
+    /// Enables a circuit breaker per [`EndpointGroup`]: after repeated network
+    /// errors, timeouts or `5xx` responses, requests to that group fail fast
+    /// with [`Error::CircuitOpen`] until a probe succeeds. Disabled by default.
+    ///
+    /// See [`breaker`] for details.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Obtains access tokens from `provider` instead of the static access token.
+    ///
+    /// The provider is consulted on every request, and asked to refresh once
+    /// when the API responds with `401 Unauthorized`.
+    pub fn credentials<P: CredentialsProvider + 'static>(mut self, provider: P) -> Self {
+        self.credentials = Arc::new(provider);
+        self
+    }
+
+    /// Authenticates with a seller's OAuth session instead of the static access token.
+    ///
+    /// The session refreshes the token before it expires, so the client can be
+    /// kept for as long as the seller's authorization is valid.
+    pub fn oauth_session(self, session: OAuthSession) -> Self {
+        self.credentials(session)
+    }
+
+    /// Records the client's requests to `cassette`, or replays them from it,
+    /// depending on its [`Mode`](crate::testing::cassette::Mode).
+    ///
+    /// See [`cassette`](crate::testing::cassette) for details.
+    #[cfg(feature = "testing")]
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
     /// Builds the `MercadoPagoClient`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the HTTP client fails to initialize.
+    /// Returns [`Error::Internal`] if a [`RateLimit`](ratelimit::RateLimit)
+    /// has a rate that is not positive and finite, or an error if the HTTP
+    /// client fails to initialize.
     pub fn build(self) -> Result<MercadoPagoClient, Error> {
+        if let Some(config) = &self.rate_limiter {
+            config.validate()?;
+        }
         let client = Client::builder()
             .timeout(self.timeout)
             .connect_timeout(self.connect_timeout)
@@ -382,9 +1073,20 @@ impl MercadoPagoClientBuilder {
         Ok(MercadoPagoClient {
             inner: Arc::new(MercadoPagoClientInner {
                 client,
-                access_token: self.access_token,
+                credentials: self.credentials,
                 base_url: self.base_url,
                 max_retries: self.max_retries,
+                user_id: OnceCell::new(),
+                breaker: self
+                    .circuit_breaker
+                    .map(|config| Arc::new(CircuitBreaker::new(config))),
+                rate_limiter: self
+                    .rate_limiter
+                    .map(|config| Arc::new(RateLimiter::new(config))),
+                max_retry_wait: self.max_retry_wait,
+                stats: Arc::default(),
+                #[cfg(feature = "testing")]
+                cassette: self.cassette,
             }),
         })
     }
@@ -394,6 +1096,7 @@ impl MercadoPagoClientBuilder {
 pub struct MercadoPagoRequestBuilder {
     builder: RequestBuilder,
     client: MercadoPagoClient,
+    operation: Operation,
 }
 
 impl MercadoPagoRequestBuilder {
@@ -409,6 +1112,20 @@ impl MercadoPagoRequestBuilder {
         self
     }
 
+    /// Sets the `X-Idempotency-Key` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `key` is empty or not a valid header value.
+    pub fn idempotency_key(mut self, key: &str) -> Result<Self, Error> {
+        let value = reqwest::header::HeaderValue::from_str(key)
+            .ok()
+            .filter(|_| !key.trim().is_empty())
+            .ok_or_else(|| Error::Internal(format!("Invalid idempotency key: {:?}", key)))?;
+        self.operation.idempotent = true;
+        Ok(self.header(IDEMPOTENCY_KEY, value))
+    }
+
     /// Adds query parameters to the request.
     pub fn query<T: serde::Serialize + ?Sized>(mut self, query: &T) -> Self {
         self.builder = self.builder.query(query);
@@ -421,34 +1138,75 @@ impl MercadoPagoRequestBuilder {
         self
     }
 
+    /// Sets a raw body, e.g., an encoded multipart form.
+    pub(crate) fn body(mut self, body: Vec<u8>) -> Self {
+        self.builder = self.builder.body(body);
+        self
+    }
+
     /// Sends the request and returns the response.
     ///
     /// # Errors
     ///
     /// Returns an error if the network request fails or if the API returns an error status code.
     pub async fn send(self) -> Result<Response, Error> {
-        let res = self.client.execute_with_retry(self.builder).await?;
+        let MercadoPagoRequestBuilder {
+            builder,
+            client,
+            operation,
+        } = self;
+        let started = Instant::now();
 
-        if res.status().is_success() {
-            #[cfg(feature = "logging")]
-            {
-                // Note: This consumes the body, so we can only do it if we return the body or clone it.
-                // For temporary debugging, we'll just be careful.
-            }
-            Ok(res)
-        } else {
-            let status = res.status();
-            let body = res
-                .text()
-                .await
-                .map_err(|e| Error::Network(e.to_string()))?;
-            let api_error: Error = serde_json::from_str(&body).unwrap_or_else(|_| {
-                Error::Internal(format!(
-                    "Failed to parse API error: {} (Status: {})",
-                    body, status
-                ))
-            });
-            Err(api_error)
-        }
+        #[cfg(feature = "logging")]
+        let result = {
+            use tracing::Instrument;
+
+            let span = telemetry::request_span(&operation);
+            let result = client
+                .send_operation(builder, &operation)
+                .instrument(span.clone())
+                .await;
+            telemetry::record_outcome(&span, &result);
+            result
+        };
+        #[cfg(not(feature = "logging"))]
+        let result = client.send_operation(builder, &operation).await;
+
+        client.inner.stats.record_request(
+            &operation.method,
+            &operation.route,
+            &result,
+            started.elapsed(),
+        );
+        result
+    }
+
+    /// Sends the request and deserializes the JSON body into `T`, keeping the
+    /// status, headers and raw JSON in an [`ApiResponse`].
+    ///
+    /// ```no_run
+    /// # async fn run(client: mercadopago_sdk::MercadoPagoClient) -> Result<(), mercadopago_sdk::Error> {
+    /// use mercadopago_sdk::models::payments::PaymentResponse;
+    ///
+    /// let res = client
+    ///     .get("/v1/payments/123")
+    ///     .send_json::<PaymentResponse>()
+    ///     .await?;
+    /// println!("{} ({:?})", res.data.status, res.request_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, if the API returns an error
+    /// status code, or [`Error::Serialization`] with the JSON path of the
+    /// offending field and a snippet of the body if it can't be decoded.
+    pub async fn send_json<T: DeserializeOwned>(self) -> Result<ApiResponse<T>, Error> {
+        let res = self.send().await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.text().await?;
+        ApiResponse::decode(status, headers, &body)
     }
 }
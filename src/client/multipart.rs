@@ -0,0 +1,69 @@
+//! Minimal `multipart/form-data` encoder.
+//!
+//! Requests are cloned on every retry, which streaming bodies (such as
+//! `reqwest::multipart::Form`) do not support, so forms are encoded into a
+//! single buffer up front.
+
+use super::random_bytes;
+use crate::error::Error;
+
+/// A `multipart/form-data` body being built.
+pub(crate) struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+}
+
+impl MultipartForm {
+    pub(crate) fn new() -> Result<Self, Error> {
+        let boundary =
+            random_bytes::<12>()?
+                .iter()
+                .fold(String::from("mercadopago-sdk-"), |mut s, b| {
+                    s.push_str(&format!("{:02x}", b));
+                    s
+                });
+        Ok(Self {
+            boundary,
+            body: Vec::new(),
+        })
+    }
+
+    /// Appends a file field.
+    pub(crate) fn file(
+        mut self,
+        name: &str,
+        file_name: &str,
+        content_type: &str,
+        content: &[u8],
+    ) -> Self {
+        let header = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            self.boundary,
+            quote(name),
+            quote(file_name),
+            content_type.replace(['\r', '\n'], "")
+        );
+        self.body.extend_from_slice(header.as_bytes());
+        self.body.extend_from_slice(content);
+        self.body.extend_from_slice(b"\r\n");
+        self
+    }
+
+    /// Returns the `Content-Type` header value and the encoded body.
+    pub(crate) fn finish(mut self) -> (String, Vec<u8>) {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        (
+            format!("multipart/form-data; boundary={}", self.boundary),
+            self.body,
+        )
+    }
+}
+
+/// Escapes a `Content-Disposition` parameter the way browsers do.
+fn quote(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
@@ -0,0 +1,191 @@
+//! Per-seller clients sharing one connection pool.
+//!
+//! Platforms that process payments on behalf of many sellers need one access
+//! token per seller (collector). [`ClientPool`] hands out a
+//! [`MercadoPagoClient`] per collector that reuses the HTTP connection pool,
+//! base URL and retry settings of a base client, and loads the collector's
+//! credentials lazily through a [`TokenLoader`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use async_trait::async_trait;
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::client::pool::{ClientPool, TokenLoader};
+//! use mercadopago_sdk::credentials::{CredentialsProvider, StaticCredentials};
+//! use mercadopago_sdk::error::Error;
+//! use std::sync::Arc;
+//!
+//! struct DbTokens;
+//!
+//! #[async_trait]
+//! impl TokenLoader for DbTokens {
+//!     async fn load(&self, collector_id: u64) -> Result<Arc<dyn CredentialsProvider>, Error> {
+//!         let token = format!("token-of-{}", collector_id); // query your storage here
+//!         Ok(Arc::new(StaticCredentials::new(&token)))
+//!     }
+//! }
+//!
+//! # async fn run() -> Result<(), Error> {
+//! let base = MercadoPagoClient::builder("").build()?;
+//! let pool = ClientPool::new(base, DbTokens).capacity(10_000);
+//!
+//! let stores = pool.for_collector(123456).search_stores(123456).await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::client::MercadoPagoClient;
+use crate::credentials::{CredentialsProvider, ExposeSecret, SecretString};
+use crate::error::Error;
+use async_trait::async_trait;
+use lru::LruCache;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// Loads the credentials of a collector, e.g. from a database or secret store.
+#[async_trait]
+pub trait TokenLoader: Send + Sync {
+    /// Returns the credentials provider for `collector_id`.
+    ///
+    /// Called on the first request made through a collector's client, and
+    /// again when a request is rejected with `401 Unauthorized` and the
+    /// provider cannot refresh itself.
+    async fn load(&self, collector_id: u64) -> Result<Arc<dyn CredentialsProvider>, Error>;
+}
+
+/// Credentials of one collector, loaded on first use.
+struct CollectorCredentials {
+    collector_id: u64,
+    loader: Arc<dyn TokenLoader>,
+    provider: RwLock<Option<Arc<dyn CredentialsProvider>>>,
+}
+
+impl CollectorCredentials {
+    async fn provider(&self) -> Result<Arc<dyn CredentialsProvider>, Error> {
+        if let Some(provider) = self.provider.read().await.clone() {
+            return Ok(provider);
+        }
+
+        let mut slot = self.provider.write().await;
+        if let Some(provider) = slot.clone() {
+            return Ok(provider);
+        }
+        let provider = self.loader.load(self.collector_id).await?;
+        *slot = Some(provider.clone());
+        Ok(provider)
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for CollectorCredentials {
+    async fn token(&self) -> Result<SecretString, Error> {
+        self.provider().await?.token().await
+    }
+
+    async fn refresh(&self, rejected: &SecretString) -> Result<bool, Error> {
+        let current = self.provider().await?;
+        if current.refresh(rejected).await? {
+            return Ok(true);
+        }
+
+        // The token may have been rotated in storage by another process.
+        let reloaded = self.loader.load(self.collector_id).await?;
+        let new = reloaded.token().await?;
+        *self.provider.write().await = Some(reloaded);
+        Ok(new.expose_secret() != rejected.expose_secret())
+    }
+}
+
+/// A bounded cache of per-collector clients sharing one connection pool.
+///
+/// The least recently used clients are evicted when the pool is full. Clients
+/// already handed out keep working after eviction; the next
+/// [`for_collector`](ClientPool::for_collector) call simply loads the
+/// credentials again.
+#[derive(Clone)]
+pub struct ClientPool {
+    base: MercadoPagoClient,
+    loader: Arc<dyn TokenLoader>,
+    clients: Arc<Mutex<LruCache<u64, MercadoPagoClient>>>,
+}
+
+impl ClientPool {
+    /// Creates a pool whose clients share `base`'s connection pool and settings.
+    ///
+    /// Holds up to 1000 clients by default.
+    pub fn new<L: TokenLoader + 'static>(base: MercadoPagoClient, loader: L) -> Self {
+        Self {
+            base,
+            loader: Arc::new(loader),
+            clients: Arc::new(Mutex::new(LruCache::new(
+                NonZeroUsize::new(DEFAULT_CAPACITY).unwrap_or(NonZeroUsize::MIN),
+            ))),
+        }
+    }
+
+    /// Sets the maximum number of cached clients. Defaults to 1000.
+    pub fn capacity(self, capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            clients: Arc::new(Mutex::new(LruCache::new(capacity))),
+            ..self
+        }
+    }
+
+    /// Returns the client for `collector_id`.
+    ///
+    /// Credentials are loaded through the [`TokenLoader`] on the first request
+    /// made with the returned client, so this call never blocks on storage.
+    pub fn for_collector(&self, collector_id: u64) -> MercadoPagoClient {
+        let mut clients = self.lock();
+        if let Some(client) = clients.get(&collector_id) {
+            return client.clone();
+        }
+
+        let client = self.base.with_credentials(Arc::new(CollectorCredentials {
+            collector_id,
+            loader: self.loader.clone(),
+            provider: RwLock::new(None),
+        }));
+        clients.put(collector_id, client.clone());
+        client
+    }
+
+    /// Registers known credentials for `collector_id`, replacing any cached client.
+    pub fn insert(
+        &self,
+        collector_id: u64,
+        provider: Arc<dyn CredentialsProvider>,
+    ) -> MercadoPagoClient {
+        let client = self.base.with_credentials(Arc::new(CollectorCredentials {
+            collector_id,
+            loader: self.loader.clone(),
+            provider: RwLock::new(Some(provider)),
+        }));
+        self.lock().put(collector_id, client.clone());
+        client
+    }
+
+    /// Drops the cached client of `collector_id` (e.g., after the seller revokes access).
+    pub fn invalidate(&self, collector_id: u64) {
+        self.lock().pop(&collector_id);
+    }
+
+    /// Returns the number of cached clients.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no client is cached.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<u64, MercadoPagoClient>> {
+        // The cache holds no invariants a panic could break.
+        self.clients.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
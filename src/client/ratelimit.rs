@@ -0,0 +1,268 @@
+//! Client-side rate limiting and `Retry-After` parsing.
+//!
+//! Enable the limiter with
+//! [`MercadoPagoClientBuilder::rate_limiter`](super::MercadoPagoClientBuilder::rate_limiter).
+//! Each [`EndpointGroup`] gets a token bucket, shared by every clone of the
+//! client, so a batch job can be throttled below Mercado Pago's limits without
+//! starving live traffic to other groups. Requests wait for a token before
+//! they are sent.
+//!
+//! The limiter also adapts to the API: when a response reports that the quota
+//! is exhausted (`X-RateLimit-Remaining: 0` with `X-RateLimit-Reset`, or the
+//! `RateLimit-*` equivalents) or a `429` carries `Retry-After`, the whole group
+//! pauses until then.
+
+use super::breaker::EndpointGroup;
+use crate::error::Error;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+use tokio::time::{Instant, sleep_until};
+
+/// Stand-in for "never" when a delay does not fit in an `Instant` (roughly 30
+/// years, as Tokio uses for unbounded sleeps).
+const FAR_FUTURE: Duration = Duration::from_secs(86_400 * 365 * 30);
+
+/// Sustained rate and burst size of a token bucket.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    per_second: f64,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// Allows `requests` per second, with a burst of the same size (at least 1).
+    ///
+    /// `requests` must be positive and finite;
+    /// [`MercadoPagoClientBuilder::build`](super::MercadoPagoClientBuilder::build)
+    /// rejects other values.
+    pub fn per_second(requests: f64) -> Self {
+        Self {
+            per_second: requests,
+            burst: (requests.ceil() as u32).max(1),
+        }
+    }
+
+    /// Allows `requests` per minute, with a burst of one request.
+    ///
+    /// `requests` must be positive and finite, as for
+    /// [`per_second`](Self::per_second).
+    pub fn per_minute(requests: f64) -> Self {
+        Self::per_second(requests / 60.0).burst(1)
+    }
+
+    /// Sets how many requests may be sent at once after an idle period.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Per-group limits for the client-side rate limiter.
+///
+/// ```
+/// use mercadopago_sdk::client::breaker::EndpointGroup;
+/// use mercadopago_sdk::client::ratelimit::{RateLimit, RateLimiterConfig};
+///
+/// // At most 20 req/s overall, and 5 req/s for payment searches of a batch job.
+/// let config = RateLimiterConfig::new()
+///     .default_limit(RateLimit::per_second(20.0))
+///     .group(EndpointGroup::Payments, RateLimit::per_second(5.0).burst(10));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiterConfig {
+    default: Option<RateLimit>,
+    groups: HashMap<EndpointGroup, RateLimit>,
+}
+
+impl RateLimiterConfig {
+    /// Creates a configuration without limits; add them with
+    /// [`default_limit`](Self::default_limit) and [`group`](Self::group).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit for groups without their own [`group`](Self::group) limit.
+    pub fn default_limit(mut self, limit: RateLimit) -> Self {
+        self.default = Some(limit);
+        self
+    }
+
+    /// Limit for a specific endpoint group.
+    pub fn group(mut self, group: EndpointGroup, limit: RateLimit) -> Self {
+        self.groups.insert(group, limit);
+        self
+    }
+
+    fn limit_for(&self, group: EndpointGroup) -> Option<RateLimit> {
+        self.groups.get(&group).copied().or(self.default)
+    }
+
+    /// Rejects rates that are zero, negative or not finite.
+    pub(crate) fn validate(&self) -> Result<(), Error> {
+        let limits = self.default.iter().chain(self.groups.values());
+        match limits
+            .map(|limit| limit.per_second)
+            .find(|rate| !(rate.is_finite() && *rate > 0.0))
+        {
+            Some(rate) => Err(Error::Internal(format!(
+                "Invalid rate limit {} requests per second: must be positive and finite",
+                rate
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl Bucket {
+    fn new(limit: Option<RateLimit>, now: Instant) -> Self {
+        Self {
+            tokens: limit.map_or(0.0, |l| f64::from(l.burst)),
+            refilled_at: now,
+            paused_until: None,
+        }
+    }
+}
+
+/// The limiter shared by a client and its clones.
+pub(crate) struct RateLimiter {
+    config: RateLimiterConfig,
+    buckets: Mutex<HashMap<EndpointGroup, Bucket>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: RateLimiterConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until a request to `group` may be sent. Returns `true` if the
+    /// request had to wait.
+    pub(crate) async fn acquire(&self, group: EndpointGroup) -> bool {
+        let Some(ready_at) = self.reserve(group) else {
+            return false;
+        };
+
+        #[cfg(feature = "logging")]
+        tracing::debug!(
+            "Rate limiter delaying {} request by {:?}",
+            group,
+            ready_at.saturating_duration_since(Instant::now())
+        );
+
+        sleep_until(ready_at).await;
+        true
+    }
+
+    /// Takes a token, going into debt if the bucket is empty, and returns when
+    /// the request may be sent. Debt makes concurrent callers queue up in order.
+    fn reserve(&self, group: EndpointGroup) -> Option<Instant> {
+        let limit = self.config.limit_for(group);
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let bucket = buckets
+            .entry(group)
+            .or_insert_with(|| Bucket::new(limit, now));
+
+        let paused_until = bucket.paused_until.filter(|until| *until > now);
+        let Some(limit) = limit else {
+            return paused_until;
+        };
+
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * limit.per_second).min(f64::from(limit.burst));
+        bucket.refilled_at = now;
+        bucket.tokens -= 1.0;
+
+        let start = paused_until.unwrap_or(now);
+        if bucket.tokens >= 0.0 && paused_until.is_none() {
+            return None;
+        }
+        let debt = (-bucket.tokens).max(0.0) / limit.per_second;
+        let delay = Duration::try_from_secs_f64(debt).unwrap_or(FAR_FUTURE);
+        Some(
+            start
+                .checked_add(delay)
+                .unwrap_or_else(|| start + FAR_FUTURE),
+        )
+    }
+
+    /// Pauses `group` until `until`, unless it is already paused for longer.
+    pub(crate) fn pause(&self, group: EndpointGroup, until: Instant) {
+        let limit = self.config.limit_for(group);
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = buckets
+            .entry(group)
+            .or_insert_with(|| Bucket::new(limit, Instant::now()));
+        if bucket.paused_until.is_none_or(|current| current < until) {
+            bucket.paused_until = Some(until);
+        }
+    }
+
+    /// Pauses `group` if the response says its quota is exhausted.
+    pub(crate) fn observe(&self, group: EndpointGroup, headers: &HeaderMap, max_wait: Duration) {
+        let remaining = header_u64(headers, "x-ratelimit-remaining")
+            .or_else(|| header_u64(headers, "ratelimit-remaining"));
+        if remaining != Some(0) {
+            return;
+        }
+
+        let reset = header_u64(headers, "x-ratelimit-reset")
+            .or_else(|| header_u64(headers, "ratelimit-reset"))
+            .map(reset_delay)
+            .unwrap_or(Duration::from_secs(1));
+        self.pause(group, Instant::now() + reset.min(max_wait));
+    }
+}
+
+/// `*-Reset` is either seconds until the reset or a Unix timestamp.
+fn reset_delay(value: u64) -> Duration {
+    const EPOCH_THRESHOLD: u64 = 1_000_000_000;
+
+    if value < EPOCH_THRESHOLD {
+        return Duration::from_secs(value);
+    }
+    let reset = SystemTime::UNIX_EPOCH + Duration::from_secs(value);
+    reset
+        .duration_since(SystemTime::now())
+        .unwrap_or(Duration::ZERO)
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| s.trim().parse().ok())
+}
+
+/// Parses a `Retry-After` value: delay seconds or an HTTP-date.
+///
+/// ```
+/// use mercadopago_sdk::client::ratelimit::parse_retry_after;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+/// // A date in the past means "retry now".
+/// assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+/// assert_eq!(parse_retry_after("soon"), None);
+/// ```
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
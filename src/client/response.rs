@@ -0,0 +1,122 @@
+use crate::error::Error;
+use crate::redact;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+/// How much of the body is quoted in decode errors.
+const SNIPPET_LEN: usize = 200;
+
+/// A decoded API response together with its status, headers and raw JSON.
+///
+/// Returned by [`MercadoPagoRequestBuilder::send_json`](super::MercadoPagoRequestBuilder::send_json)
+/// and the `*_with_response` methods. `raw` keeps the fields the typed model
+/// doesn't know about.
+///
+/// Its `Debug` output masks personal data in `raw`; see [`redact`](crate::redact).
+#[derive(Clone)]
+pub struct ApiResponse<T> {
+    /// The deserialized body.
+    pub data: T,
+    /// HTTP status code of the response.
+    pub status: StatusCode,
+    /// Response headers, e.g., `x-request-id`, rate-limit headers or `Date`.
+    pub headers: HeaderMap,
+    /// The body as untyped JSON (`Null` for an empty body).
+    pub raw: serde_json::Value,
+}
+
+impl<T> ApiResponse<T> {
+    /// Returns the value of a header, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Returns the `x-request-id` header, to quote to Mercado Pago support.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-request-id")
+    }
+
+    /// Discards the metadata and returns the deserialized body.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ApiResponse<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut raw = self.raw.clone();
+        redact::redact_json(&mut raw);
+        f.debug_struct("ApiResponse")
+            .field("data", &self.data)
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("raw", &raw)
+            .finish()
+    }
+}
+
+impl<T: DeserializeOwned> ApiResponse<T> {
+    /// Decodes `body`, reporting failures with the JSON path and a body snippet.
+    pub(crate) fn decode(
+        status: StatusCode,
+        headers: HeaderMap,
+        body: &str,
+    ) -> Result<Self, Error> {
+        let raw = if body.trim().is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_str(body).map_err(|e| {
+                DecodeError::error(
+                    format!(
+                        "Invalid JSON response: {} (body: {})",
+                        e,
+                        snippet(&redact::redact_body(body))
+                    ),
+                    e,
+                )
+            })?
+        };
+
+        let data = serde_path_to_error::deserialize(&raw).map_err(|e| {
+            let message = format!(
+                "Failed to decode response at `{}`: {} (body: {})",
+                e.path(),
+                e.inner(),
+                snippet(&redact::redact_body(body))
+            );
+            DecodeError::error(message, e.into_inner())
+        })?;
+
+        Ok(Self {
+            data,
+            status,
+            headers,
+            raw,
+        })
+    }
+}
+
+/// A decode failure described with its JSON path and a body snippet, keeping
+/// the `serde_json::Error` as its source.
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+struct DecodeError {
+    message: String,
+    #[source]
+    source: serde_json::Error,
+}
+
+impl DecodeError {
+    fn error(message: String, source: serde_json::Error) -> Error {
+        Error::Serialization(Box::new(Self { message, source }))
+    }
+}
+
+fn snippet(body: &str) -> String {
+    match body.char_indices().nth(SNIPPET_LEN) {
+        Some((end, _)) => format!("{}…", &body[..end]),
+        None => body.to_string(),
+    }
+}
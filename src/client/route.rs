@@ -0,0 +1,80 @@
+//! Templated routes used to label spans and metrics.
+
+/// Routes of the endpoints the SDK calls, with `{id}` in place of every
+/// identifier. Literal segments are listed before the `{id}` routes they
+/// could be mistaken for (e.g. `/v1/payments/search`).
+const ROUTES: &[&str] = &[
+    "/v1/payments",
+    "/v1/payments/search",
+    "/v1/payments/{id}",
+    "/v1/payments/{id}/refunds",
+    "/v1/orders",
+    "/v1/orders/{id}",
+    "/v1/orders/{id}/process",
+    "/v1/orders/{id}/cancel",
+    "/v1/orders/{id}/refund",
+    "/v1/chargebacks/search",
+    "/v1/chargebacks/{id}",
+    "/v1/chargebacks/{id}/documentation",
+    "/v1/account/settlement_report",
+    "/v1/account/settlement_report/config",
+    "/v1/account/settlement_report/list",
+    "/v1/account/settlement_report/{id}",
+    "/v1/account/release_report",
+    "/v1/account/release_report/config",
+    "/v1/account/release_report/list",
+    "/v1/account/release_report/{id}",
+    "/checkout/preferences",
+    "/instore/orders/qr/seller/collectors/{id}/pos/{id}/qrs",
+    "/pos",
+    "/users/me",
+    "/users/test_user",
+    "/users/{id}/stores",
+    "/users/{id}/stores/search",
+    "/point/integration-api/devices",
+    "/point/integration-api/devices/{id}",
+    "/point/integration-api/devices/{id}/payment-intents",
+    "/point/integration-api/devices/{id}/payment-intents/{id}",
+    "/point/integration-api/payment-intents/events",
+    "/point/integration-api/payment-intents/{id}",
+    "/oauth/token",
+];
+
+/// Route reported for paths outside [`ROUTES`], e.g. those passed to
+/// [`MercadoPagoClient::request`](super::MercadoPagoClient::request).
+const OTHER_ROUTE: &str = "{other}";
+
+/// Returns the template of a known endpoint, e.g. `/v1/payments/123/refunds`
+/// becomes `/v1/payments/{id}/refunds`. Full URLs are reduced to their path.
+///
+/// Unknown paths all map to `{other}`, so labels stay bounded whatever ids
+/// (POS external ids, device ids, ...) they contain.
+pub(crate) fn route_template(path: &str) -> String {
+    let path = url_path(path);
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let path = path.trim_end_matches('/');
+
+    ROUTES
+        .iter()
+        .find(|route| matches(route, path))
+        .copied()
+        .unwrap_or(OTHER_ROUTE)
+        .to_string()
+}
+
+/// Returns the path of a full URL, or `path` itself if it has no scheme.
+pub(crate) fn url_path(path: &str) -> &str {
+    match path.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |i| &rest[i..]),
+        None => path,
+    }
+}
+
+fn matches(route: &str, path: &str) -> bool {
+    let mut segments = path.split('/');
+    route.split('/').all(|expected| match segments.next() {
+        Some(segment) if expected == "{id}" => !segment.is_empty(),
+        Some(segment) => segment == expected,
+        None => false,
+    }) && segments.next().is_none()
+}
@@ -0,0 +1,315 @@
+//! Request statistics, as a snapshot ([`MercadoPagoClient::stats`](super::MercadoPagoClient::stats))
+//! and, with the `metrics` feature, through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! The facade emits:
+//!
+//! | Metric | Type | Labels |
+//! |---|---|---|
+//! | `mercadopago_requests_total` | counter | `method`, `route`, `status_class` (`2xx`, `3xx`, `4xx`, `5xx`, `error`) |
+//! | `mercadopago_request_duration_seconds` | histogram | `method`, `route` |
+//! | `mercadopago_retries_total` | counter | `method`, `route`, `reason` (`rate_limited`, `unauthorized`) |
+//! | `mercadopago_rejections_total` | counter | `group`, `reason` (`circuit_open`, `rate_limited`) |
+//!
+//! `route` is the templated path (`/v1/payments/{id}`), so label cardinality
+//! stays bounded. Latency covers the whole call, including retries.
+//! `rate_limited` rejections count requests delayed by the client-side limiter.
+
+use super::breaker::EndpointGroup;
+use crate::error::Error;
+use reqwest::{Method, Response};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// A snapshot of the requests made by a client and its clones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClientStats {
+    /// Requests sent, counting each call once regardless of retries.
+    pub requests: u64,
+    /// Calls that ended with a `2xx` response.
+    pub successes: u64,
+    /// Calls that ended with a `3xx` response not followed as a redirect
+    /// (e.g., `304 Not Modified`).
+    pub redirects: u64,
+    /// Calls that ended with a `4xx` response.
+    pub client_errors: u64,
+    /// Calls that ended with a `5xx` response.
+    pub server_errors: u64,
+    /// Calls that failed without a response (network errors, timeouts).
+    pub transport_errors: u64,
+    /// Retries after a `429` or a `401` with refreshed credentials.
+    pub retries: u64,
+    /// Calls rejected by an open circuit breaker; not counted in `requests`.
+    pub circuit_rejections: u64,
+    /// Attempts delayed by the client-side rate limiter.
+    pub rate_limit_delays: u64,
+    /// Per-operation figures, keyed by method and route
+    /// (e.g., `"GET /v1/payments/{id}"`).
+    pub routes: BTreeMap<String, RouteStats>,
+}
+
+/// Figures for a single operation in [`ClientStats`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteStats {
+    /// Calls made.
+    pub requests: u64,
+    /// Calls that did not end with a `2xx` response.
+    pub errors: u64,
+    /// Sum of the latencies of all calls.
+    pub total_latency: Duration,
+    /// Slowest call.
+    pub max_latency: Duration,
+}
+
+impl RouteStats {
+    /// Average latency per call.
+    pub fn mean_latency(&self) -> Duration {
+        match u32::try_from(self.requests) {
+            Ok(0) => Duration::ZERO,
+            Ok(requests) => self.total_latency / requests,
+            Err(_) => {
+                Duration::from_secs_f64(self.total_latency.as_secs_f64() / self.requests as f64)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RetryReason {
+    RateLimited,
+    Unauthorized,
+}
+
+impl RetryReason {
+    #[cfg(feature = "metrics")]
+    fn as_str(self) -> &'static str {
+        match self {
+            RetryReason::RateLimited => "rate_limited",
+            RetryReason::Unauthorized => "unauthorized",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Rejection {
+    CircuitOpen,
+    RateLimited,
+}
+
+impl Rejection {
+    #[cfg(feature = "metrics")]
+    fn as_str(self) -> &'static str {
+        match self {
+            Rejection::CircuitOpen => "circuit_open",
+            Rejection::RateLimited => "rate_limited",
+        }
+    }
+}
+
+/// Collects [`ClientStats`] and forwards them to the `metrics` facade.
+///
+/// Totals are atomics, and per-route figures sit behind a read-mostly lock
+/// that is only taken for writing the first time a route is called, so
+/// recording a call neither blocks concurrent calls nor allocates.
+#[derive(Default)]
+pub(crate) struct StatsRecorder {
+    totals: Totals,
+    routes: RwLock<HashMap<Method, HashMap<String, RouteCounters>>>,
+}
+
+#[derive(Default)]
+struct Totals {
+    requests: AtomicU64,
+    successes: AtomicU64,
+    redirects: AtomicU64,
+    client_errors: AtomicU64,
+    server_errors: AtomicU64,
+    transport_errors: AtomicU64,
+    retries: AtomicU64,
+    circuit_rejections: AtomicU64,
+    rate_limit_delays: AtomicU64,
+}
+
+#[derive(Default)]
+struct RouteCounters {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    total_latency_nanos: AtomicU64,
+    max_latency_nanos: AtomicU64,
+}
+
+impl RouteCounters {
+    fn record(&self, failed: bool, latency: Duration) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        self.total_latency_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_latency_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> RouteStats {
+        RouteStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            total_latency: Duration::from_nanos(self.total_latency_nanos.load(Ordering::Relaxed)),
+            max_latency: Duration::from_nanos(self.max_latency_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatusClass {
+    Success,
+    Redirect,
+    ClientError,
+    ServerError,
+    Transport,
+}
+
+impl StatusClass {
+    fn for_status(status: Option<u16>) -> Self {
+        match status {
+            Some(200..=299) => StatusClass::Success,
+            Some(300..=399) => StatusClass::Redirect,
+            Some(400..=499) => StatusClass::ClientError,
+            Some(500..=599) => StatusClass::ServerError,
+            _ => StatusClass::Transport,
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    fn as_str(self) -> &'static str {
+        match self {
+            StatusClass::Success => "2xx",
+            StatusClass::Redirect => "3xx",
+            StatusClass::ClientError => "4xx",
+            StatusClass::ServerError => "5xx",
+            StatusClass::Transport => "error",
+        }
+    }
+}
+
+impl StatsRecorder {
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        let totals = &self.totals;
+        let routes = self.routes.read().unwrap_or_else(|e| e.into_inner());
+        ClientStats {
+            requests: totals.requests.load(Ordering::Relaxed),
+            successes: totals.successes.load(Ordering::Relaxed),
+            redirects: totals.redirects.load(Ordering::Relaxed),
+            client_errors: totals.client_errors.load(Ordering::Relaxed),
+            server_errors: totals.server_errors.load(Ordering::Relaxed),
+            transport_errors: totals.transport_errors.load(Ordering::Relaxed),
+            retries: totals.retries.load(Ordering::Relaxed),
+            circuit_rejections: totals.circuit_rejections.load(Ordering::Relaxed),
+            rate_limit_delays: totals.rate_limit_delays.load(Ordering::Relaxed),
+            routes: routes
+                .iter()
+                .flat_map(|(method, routes)| {
+                    routes.iter().map(move |(route, counters)| {
+                        (format!("{} {}", method, route), counters.snapshot())
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Records a finished call. Circuit breaker rejections are recorded by
+    /// [`record_rejection`](Self::record_rejection) instead.
+    pub(crate) fn record_request(
+        &self,
+        method: &Method,
+        route: &str,
+        result: &Result<Response, Error>,
+        latency: Duration,
+    ) {
+        let status = match result {
+            Ok(res) => Some(res.status().as_u16()),
+            Err(Error::CircuitOpen { .. }) => return,
+            Err(err) => err.status(),
+        };
+        let status_class = StatusClass::for_status(status);
+
+        #[cfg(feature = "metrics")]
+        {
+            let labels = [
+                ("method", method.as_str().to_string()),
+                ("route", route.to_string()),
+            ];
+            metrics::counter!(
+                "mercadopago_requests_total",
+                &[
+                    labels[0].clone(),
+                    labels[1].clone(),
+                    ("status_class", status_class.as_str().to_string()),
+                ]
+            )
+            .increment(1);
+            metrics::histogram!("mercadopago_request_duration_seconds", &labels)
+                .record(latency.as_secs_f64());
+        }
+
+        let totals = &self.totals;
+        totals.requests.fetch_add(1, Ordering::Relaxed);
+        let counter = match status_class {
+            StatusClass::Success => &totals.successes,
+            StatusClass::Redirect => &totals.redirects,
+            StatusClass::ClientError => &totals.client_errors,
+            StatusClass::ServerError => &totals.server_errors,
+            StatusClass::Transport => &totals.transport_errors,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        let failed = status_class != StatusClass::Success;
+        {
+            let routes = self.routes.read().unwrap_or_else(|e| e.into_inner());
+            if let Some(counters) = routes.get(method).and_then(|routes| routes.get(route)) {
+                counters.record(failed, latency);
+                return;
+            }
+        }
+        let mut routes = self.routes.write().unwrap_or_else(|e| e.into_inner());
+        routes
+            .entry(method.clone())
+            .or_default()
+            .entry(route.to_string())
+            .or_default()
+            .record(failed, latency);
+    }
+
+    pub(crate) fn record_retry(&self, method: &Method, route: &str, reason: RetryReason) {
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "mercadopago_retries_total",
+            "method" => method.as_str().to_string(),
+            "route" => route.to_string(),
+            "reason" => reason.as_str()
+        )
+        .increment(1);
+        #[cfg(not(feature = "metrics"))]
+        let _ = (method, route, reason);
+
+        self.totals.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_rejection(&self, group: EndpointGroup, reason: Rejection) {
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "mercadopago_rejections_total",
+            "group" => group.to_string(),
+            "reason" => reason.as_str()
+        )
+        .increment(1);
+        #[cfg(not(feature = "metrics"))]
+        let _ = group;
+
+        let counter = match reason {
+            Rejection::CircuitOpen => &self.totals.circuit_rejections,
+            Rejection::RateLimited => &self.totals.rate_limit_delays,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
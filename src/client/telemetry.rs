@@ -0,0 +1,111 @@
+//! Tracing spans for API calls (`logging` feature) and W3C trace context
+//! propagation (`otel` feature).
+//!
+//! Every request is wrapped in a `mercadopago.request` span named after the
+//! method and the templated route (`GET /v1/payments/{id}`), so traces group by
+//! operation rather than by resource id. Attribute names follow the
+//! OpenTelemetry HTTP client conventions where one exists.
+
+use super::Operation;
+use crate::error::{Error, ErrorKind};
+#[cfg(feature = "otel")]
+use reqwest::RequestBuilder;
+use reqwest::Response;
+use tracing::Span;
+use tracing::field::Empty;
+
+/// Creates the span for a request about to be sent.
+pub(crate) fn request_span(operation: &Operation) -> Span {
+    let method = operation.method.as_str();
+    let route = &operation.route;
+
+    tracing::info_span!(
+        "mercadopago.request",
+        otel.name = %format_args!("{} {}", method, route),
+        otel.kind = "client",
+        otel.status_code = Empty,
+        http.request.method = method,
+        url.template = %route,
+        http.response.status_code = Empty,
+        http.request.resend_count = Empty,
+        error.type = Empty,
+        mercadopago.endpoint_group = %operation.group,
+        mercadopago.idempotency_key = operation.idempotent,
+        mercadopago.request_id = Empty,
+    )
+}
+
+/// Records the status and request id of the final response on `span`.
+pub(crate) fn record_outcome(span: &Span, result: &Result<Response, Error>) {
+    match result {
+        Ok(res) => {
+            span.record("http.response.status_code", res.status().as_u16());
+            if let Some(request_id) = res
+                .headers()
+                .get("x-request-id")
+                .and_then(|v| v.to_str().ok())
+            {
+                span.record("mercadopago.request_id", request_id);
+            }
+        }
+        Err(err) => {
+            if let Some(status) = err.status() {
+                span.record("http.response.status_code", status);
+            }
+            if let Some(request_id) = err.request_id() {
+                span.record("mercadopago.request_id", request_id);
+            }
+            span.record("otel.status_code", "ERROR");
+            span.record("error.type", error_type(err));
+        }
+    }
+}
+
+/// Records a retry of the current request.
+pub(crate) fn record_resend(resend_count: u32) {
+    Span::current().record("http.request.resend_count", resend_count);
+}
+
+fn error_type(err: &Error) -> String {
+    match err.kind() {
+        ErrorKind::Timeout => "timeout".to_string(),
+        ErrorKind::Network => "network".to_string(),
+        ErrorKind::Serialization => "serialization".to_string(),
+        ErrorKind::CircuitOpen { .. } => "circuit_open".to_string(),
+        ErrorKind::Internal => "internal".to_string(),
+        _ => err
+            .status()
+            .map_or_else(|| "_OTHER".to_string(), |s| s.to_string()),
+    }
+}
+
+/// Adds W3C `traceparent` / `tracestate` headers for the current span, if it
+/// belongs to an OpenTelemetry trace.
+#[cfg(feature = "otel")]
+pub(crate) fn inject_trace_context(rb: RequestBuilder) -> RequestBuilder {
+    use opentelemetry::trace::TraceContextExt;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let cx = Span::current().context();
+    let span = cx.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return rb;
+    }
+
+    let rb = rb.header(
+        "traceparent",
+        format!(
+            "00-{}-{}-{:02x}",
+            span_context.trace_id(),
+            span_context.span_id(),
+            span_context.trace_flags().to_u8()
+        ),
+    );
+    let trace_state = span_context.trace_state().header();
+    if trace_state.is_empty() {
+        rb
+    } else {
+        rb.header("tracestate", trace_state)
+    }
+}
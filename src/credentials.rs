@@ -0,0 +1,133 @@
+//! Access token providers.
+//!
+//! [`MercadoPagoClient`](crate::MercadoPagoClient) asks its
+//! [`CredentialsProvider`] for a token on every request, so credentials can be
+//! rotated without rebuilding the client. When the API answers `401
+//! Unauthorized`, the client calls [`CredentialsProvider::refresh`] and, if it
+//! reports a new token, retries the request once.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::credentials::EnvCredentials;
+//!
+//! let client = MercadoPagoClient::builder("")
+//!     .credentials(EnvCredentials::new("MERCADO_PAGO_ACCESS_TOKEN"))
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
+//! Tokens are handled as [`SecretString`], which is zeroized when dropped and
+//! prints as `[REDACTED]` in `Debug` output. Call
+//! [`expose_secret`](ExposeSecret::expose_secret) to read the value.
+use crate::error::Error;
+use crate::oauth::OAuthSession;
+use async_trait::async_trait;
+use std::sync::RwLock;
+
+pub use secrecy::{ExposeSecret, SecretString};
+
+/// Source of access tokens for a [`MercadoPagoClient`](crate::MercadoPagoClient).
+#[async_trait]
+pub trait CredentialsProvider: Send + Sync {
+    /// Returns the access token to use for the next request.
+    async fn token(&self) -> Result<SecretString, Error>;
+
+    /// Called when the API rejects `rejected` with `401 Unauthorized`.
+    ///
+    /// Returns `true` if a new token is available and the request should be
+    /// retried. Several requests may report the same token concurrently;
+    /// compare against `rejected` to renew it only once. The default implementation returns `false`. If it returns an
+    /// error, the client logs it and returns the original `401` response.
+    async fn refresh(&self, _rejected: &SecretString) -> Result<bool, Error> {
+        Ok(false)
+    }
+}
+
+/// A fixed access token.
+#[derive(Debug, Clone)]
+pub struct StaticCredentials {
+    token: SecretString,
+}
+
+impl StaticCredentials {
+    /// Creates a provider that always returns `token`.
+    pub fn new(token: &str) -> Self {
+        Self {
+            token: SecretString::from(token),
+        }
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for StaticCredentials {
+    async fn token(&self) -> Result<SecretString, Error> {
+        Ok(self.token.clone())
+    }
+}
+
+/// An access token read from an environment variable.
+///
+/// The variable is read once and cached; after a `401` it is read again, so a
+/// token rotated by the process supervisor is picked up without a restart.
+#[derive(Debug)]
+pub struct EnvCredentials {
+    var: String,
+    cached: RwLock<Option<SecretString>>,
+}
+
+impl EnvCredentials {
+    /// Creates a provider backed by the environment variable `var`.
+    pub fn new(var: &str) -> Self {
+        Self {
+            var: var.to_string(),
+            cached: RwLock::new(None),
+        }
+    }
+
+    fn read(&self) -> Result<SecretString, Error> {
+        std::env::var(&self.var)
+            .map(SecretString::from)
+            .map_err(|e| {
+                Error::Internal(format!("Cannot read access token from {}: {}", self.var, e))
+            })
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for EnvCredentials {
+    async fn token(&self) -> Result<SecretString, Error> {
+        if let Some(token) = self.cached.read().ok().and_then(|c| c.clone()) {
+            return Ok(token);
+        }
+        let token = self.read()?;
+        if let Ok(mut cached) = self.cached.write() {
+            *cached = Some(token.clone());
+        }
+        Ok(token)
+    }
+
+    async fn refresh(&self, rejected: &SecretString) -> Result<bool, Error> {
+        let token = self.read()?;
+        let mut cached = self
+            .cached
+            .write()
+            .map_err(|_| Error::Internal("Credentials lock poisoned".to_string()))?;
+        let changed = token.expose_secret() != rejected.expose_secret();
+        *cached = Some(token);
+        Ok(changed)
+    }
+}
+
+#[async_trait]
+impl CredentialsProvider for OAuthSession {
+    async fn token(&self) -> Result<SecretString, Error> {
+        self.access_token().await
+    }
+
+    async fn refresh(&self, rejected: &SecretString) -> Result<bool, Error> {
+        self.refresh_rejected(rejected).await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,372 @@
+//! Parser and encoder for EMVCo merchant-presented QR payloads.
+//!
+//! [`QrOrderResponse::qr_data`] is an EMVCo payload: a sequence of
+//! tag-length-value fields ending with a CRC16 checksum. [`EmvcoPayload`]
+//! exposes the standard fields, verifies the checksum and can encode the
+//! payload back, so a terminal can check the amount before showing the code
+//! to the buyer.
+//!
+//! # Example
+//!
+//! ```
+//! use mercadopago_sdk::emvco::EmvcoPayload;
+//!
+//! let qr_data = "00020101021226320016COM.MERCADOLIBRE0208ABCD1234520400005303032540515.505802AR5909Mi Tienda6012Buenos Aires6304A84F";
+//! let payload = EmvcoPayload::parse(qr_data).unwrap();
+//!
+//! assert_eq!(payload.merchant_name, "Mi Tienda");
+//! assert_eq!(payload.amount(), Some(15.5));
+//! assert!(payload.matches_amount(15.5));
+//! assert_eq!(payload.encode(), qr_data);
+//! ```
+use crate::error::Error;
+use crate::models::instore::QrOrderResponse;
+use thiserror::Error;
+
+const TAG_PAYLOAD_FORMAT: u8 = 0;
+const TAG_POINT_OF_INITIATION: u8 = 1;
+const TAG_MERCHANT_CATEGORY_CODE: u8 = 52;
+const TAG_CURRENCY: u8 = 53;
+const TAG_AMOUNT: u8 = 54;
+const TAG_COUNTRY: u8 = 58;
+const TAG_MERCHANT_NAME: u8 = 59;
+const TAG_MERCHANT_CITY: u8 = 60;
+const TAG_POSTAL_CODE: u8 = 61;
+const TAG_ADDITIONAL_DATA: u8 = 62;
+const TAG_CRC: u8 = 63;
+
+/// Errors produced while parsing an EMVCo payload.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EmvcoError {
+    /// The payload ended in the middle of a field.
+    #[error("EMVCo payload truncated at position {0}")]
+    Truncated(usize),
+    /// A tag or length was not a two-digit number.
+    #[error("Invalid tag or length at position {0}")]
+    InvalidHeader(usize),
+    /// A mandatory field is missing.
+    #[error("Missing mandatory EMVCo field {0:02}")]
+    MissingField(u8),
+    /// The CRC field is missing, malformed or not the last field.
+    #[error("Invalid EMVCo CRC field")]
+    InvalidCrc,
+    /// The CRC in the payload does not match the computed one.
+    #[error("EMVCo CRC mismatch: payload has {expected:04X}, computed {actual:04X}")]
+    CrcMismatch {
+        /// Checksum carried in the payload.
+        expected: u16,
+        /// Checksum computed over the payload.
+        actual: u16,
+    },
+}
+
+impl From<EmvcoError> for Error {
+    fn from(err: EmvcoError) -> Self {
+        Error::Serialization(Box::new(err))
+    }
+}
+
+/// A raw tag-length-value field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tlv {
+    /// Two-digit field identifier.
+    pub tag: u8,
+    /// Field value.
+    pub value: String,
+}
+
+/// Merchant account information (tags 02 to 51).
+///
+/// Tags 26 to 51 are templates whose value is itself a list of TLV fields,
+/// starting with a globally unique identifier (e.g., `COM.MERCADOLIBRE`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerchantAccountInfo {
+    /// Field identifier, between 02 and 51.
+    pub tag: u8,
+    /// Raw field value.
+    pub value: String,
+}
+
+impl MerchantAccountInfo {
+    /// Returns `true` for template fields (tags 26 to 51).
+    pub fn is_template(&self) -> bool {
+        (26..=51).contains(&self.tag)
+    }
+
+    /// Parses the nested fields of a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a valid TLV list.
+    pub fn subfields(&self) -> Result<Vec<Tlv>, EmvcoError> {
+        parse_tlvs(&self.value)
+    }
+
+    /// Returns the globally unique identifier (sub-tag 00) of a template.
+    pub fn globally_unique_identifier(&self) -> Option<String> {
+        if !self.is_template() {
+            return None;
+        }
+        self.subfields()
+            .ok()?
+            .into_iter()
+            .find(|f| f.tag == 0)
+            .map(|f| f.value)
+    }
+}
+
+/// A parsed EMVCo merchant-presented QR payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmvcoPayload {
+    /// Payload format indicator (tag 00), always `"01"`.
+    pub payload_format_indicator: String,
+    /// Point of initiation method (tag 01): `"11"` static, `"12"` dynamic.
+    pub point_of_initiation_method: Option<String>,
+    /// Merchant account information fields (tags 02 to 51).
+    pub merchant_account_info: Vec<MerchantAccountInfo>,
+    /// Merchant category code (tag 52).
+    pub merchant_category_code: String,
+    /// ISO 4217 numeric currency code (tag 53), e.g. `"032"` for ARS.
+    pub transaction_currency: String,
+    /// Transaction amount as encoded (tag 54). Absent on open-amount codes.
+    pub transaction_amount: Option<String>,
+    /// ISO 3166-1 alpha-2 country code (tag 58).
+    pub country_code: String,
+    /// Merchant name (tag 59).
+    pub merchant_name: String,
+    /// Merchant city (tag 60).
+    pub merchant_city: String,
+    /// Postal code (tag 61).
+    pub postal_code: Option<String>,
+    /// Raw additional data field template (tag 62).
+    pub additional_data: Option<String>,
+    /// Any other field, kept so the payload can be encoded back.
+    pub other_fields: Vec<Tlv>,
+    /// Checksum carried in the payload (tag 63).
+    pub crc: u16,
+}
+
+impl EmvcoPayload {
+    /// Parses a payload and verifies its CRC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed, lacks a mandatory field or
+    /// the checksum does not match.
+    pub fn parse(data: &str) -> Result<Self, EmvcoError> {
+        let payload = Self::parse_unverified(data)?;
+        let actual = crc16(&data[..data.len() - 4]);
+        if payload.crc != actual {
+            return Err(EmvcoError::CrcMismatch {
+                expected: payload.crc,
+                actual,
+            });
+        }
+        Ok(payload)
+    }
+
+    /// Parses a payload without verifying its CRC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed or lacks a mandatory field.
+    pub fn parse_unverified(data: &str) -> Result<Self, EmvcoError> {
+        let mut fields = parse_tlvs(data)?;
+
+        let crc = match fields.pop() {
+            Some(Tlv {
+                tag: TAG_CRC,
+                value,
+            }) if value.len() == 4 && value.chars().all(|c| c.is_ascii_hexdigit()) => {
+                u16::from_str_radix(&value, 16).map_err(|_| EmvcoError::InvalidCrc)?
+            }
+            _ => return Err(EmvcoError::InvalidCrc),
+        };
+
+        let mut payload_format_indicator = None;
+        let mut point_of_initiation_method = None;
+        let mut merchant_account_info = Vec::new();
+        let mut merchant_category_code = None;
+        let mut transaction_currency = None;
+        let mut transaction_amount = None;
+        let mut country_code = None;
+        let mut merchant_name = None;
+        let mut merchant_city = None;
+        let mut postal_code = None;
+        let mut additional_data = None;
+        let mut other_fields = Vec::new();
+
+        for field in fields {
+            let value = field.value;
+            match field.tag {
+                TAG_PAYLOAD_FORMAT => payload_format_indicator = Some(value),
+                TAG_POINT_OF_INITIATION => point_of_initiation_method = Some(value),
+                2..=51 => merchant_account_info.push(MerchantAccountInfo {
+                    tag: field.tag,
+                    value,
+                }),
+                TAG_MERCHANT_CATEGORY_CODE => merchant_category_code = Some(value),
+                TAG_CURRENCY => transaction_currency = Some(value),
+                TAG_AMOUNT => transaction_amount = Some(value),
+                TAG_COUNTRY => country_code = Some(value),
+                TAG_MERCHANT_NAME => merchant_name = Some(value),
+                TAG_MERCHANT_CITY => merchant_city = Some(value),
+                TAG_POSTAL_CODE => postal_code = Some(value),
+                TAG_ADDITIONAL_DATA => additional_data = Some(value),
+                tag => other_fields.push(Tlv { tag, value }),
+            }
+        }
+
+        if merchant_account_info.is_empty() {
+            return Err(EmvcoError::MissingField(2));
+        }
+
+        Ok(Self {
+            payload_format_indicator: payload_format_indicator
+                .ok_or(EmvcoError::MissingField(TAG_PAYLOAD_FORMAT))?,
+            point_of_initiation_method,
+            merchant_account_info,
+            merchant_category_code: merchant_category_code
+                .ok_or(EmvcoError::MissingField(TAG_MERCHANT_CATEGORY_CODE))?,
+            transaction_currency: transaction_currency
+                .ok_or(EmvcoError::MissingField(TAG_CURRENCY))?,
+            transaction_amount,
+            country_code: country_code.ok_or(EmvcoError::MissingField(TAG_COUNTRY))?,
+            merchant_name: merchant_name.ok_or(EmvcoError::MissingField(TAG_MERCHANT_NAME))?,
+            merchant_city: merchant_city.ok_or(EmvcoError::MissingField(TAG_MERCHANT_CITY))?,
+            postal_code,
+            additional_data,
+            other_fields,
+            crc,
+        })
+    }
+
+    /// Returns the transaction amount as a number, if present and valid.
+    pub fn amount(&self) -> Option<f64> {
+        self.transaction_amount.as_deref()?.parse().ok()
+    }
+
+    /// Returns `true` if the encoded amount equals `expected` to the cent.
+    pub fn matches_amount(&self, expected: f64) -> bool {
+        self.amount()
+            .is_some_and(|amount| (amount - expected).abs() < 0.005)
+    }
+
+    /// Returns `true` if the code is dynamic (single use, point of initiation `"12"`).
+    pub fn is_dynamic(&self) -> bool {
+        self.point_of_initiation_method.as_deref() == Some("12")
+    }
+
+    /// Parses the nested fields of the additional data template (tag 62).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template is not a valid TLV list.
+    pub fn additional_data_fields(&self) -> Result<Vec<Tlv>, EmvcoError> {
+        match &self.additional_data {
+            Some(data) => parse_tlvs(data),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Encodes the payload with fields in ascending tag order and a freshly
+    /// computed CRC.
+    pub fn encode(&self) -> String {
+        let mut fields: Vec<(u8, &str)> =
+            vec![(TAG_PAYLOAD_FORMAT, &self.payload_format_indicator)];
+        if let Some(v) = &self.point_of_initiation_method {
+            fields.push((TAG_POINT_OF_INITIATION, v));
+        }
+        fields.extend(
+            self.merchant_account_info
+                .iter()
+                .map(|m| (m.tag, m.value.as_str())),
+        );
+        fields.push((TAG_MERCHANT_CATEGORY_CODE, &self.merchant_category_code));
+        fields.push((TAG_CURRENCY, &self.transaction_currency));
+        if let Some(v) = &self.transaction_amount {
+            fields.push((TAG_AMOUNT, v));
+        }
+        fields.push((TAG_COUNTRY, &self.country_code));
+        fields.push((TAG_MERCHANT_NAME, &self.merchant_name));
+        fields.push((TAG_MERCHANT_CITY, &self.merchant_city));
+        if let Some(v) = &self.postal_code {
+            fields.push((TAG_POSTAL_CODE, v));
+        }
+        if let Some(v) = &self.additional_data {
+            fields.push((TAG_ADDITIONAL_DATA, v));
+        }
+        fields.extend(self.other_fields.iter().map(|f| (f.tag, f.value.as_str())));
+        fields.sort_by_key(|(tag, _)| *tag);
+
+        let mut out = String::new();
+        for (tag, value) in fields {
+            out.push_str(&format!("{:02}{:02}{}", tag, value.chars().count(), value));
+        }
+        out.push_str("6304");
+        let crc = crc16(&out);
+        out.push_str(&format!("{:04X}", crc));
+        out
+    }
+}
+
+impl QrOrderResponse {
+    /// Parses [`qr_data`](QrOrderResponse::qr_data) as an EMVCo payload and verifies its CRC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is malformed or the checksum does not match.
+    pub fn emvco_payload(&self) -> Result<EmvcoPayload, EmvcoError> {
+        EmvcoPayload::parse(&self.qr_data)
+    }
+}
+
+/// Computes the CRC16/CCITT-FALSE checksum used by EMVCo payloads.
+pub fn crc16(data: &str) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for byte in data.bytes() {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn parse_tlvs(data: &str) -> Result<Vec<Tlv>, EmvcoError> {
+    let chars: Vec<char> = data.chars().collect();
+    let mut fields = Vec::new();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let header = |start: usize| -> Result<usize, EmvcoError> {
+            let digits = chars
+                .get(start..start + 2)
+                .ok_or(EmvcoError::Truncated(start))?;
+            digits
+                .iter()
+                .try_fold(0usize, |acc, c| {
+                    c.to_digit(10).map(|d| acc * 10 + d as usize)
+                })
+                .ok_or(EmvcoError::InvalidHeader(start))
+        };
+
+        let tag = header(pos)? as u8;
+        let len = header(pos + 2)?;
+        let start = pos + 4;
+        let value = chars
+            .get(start..start + len)
+            .ok_or(EmvcoError::Truncated(start))?;
+        fields.push(Tlv {
+            tag,
+            value: value.iter().collect(),
+        });
+        pos = start + len;
+    }
+
+    Ok(fields)
+}
@@ -1,36 +1,96 @@
+use crate::client::breaker::EndpointGroup;
+use crate::client::ratelimit::parse_retry_after;
+use crate::redact;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
+/// A boxed error, kept as the [`source`](std::error::Error::source) of
+/// [`Error::Serialization`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
 /// Represents errors that can occur when using the Mercado Pago SDK.
-#[derive(Debug, PartialEq, Error, Deserialize, Serialize)]
-#[serde(untagged)]
+///
+/// Use [`Error::kind`] to branch on the category of a failure, and
+/// [`Error::is_retryable`] / [`Error::is_client_error`] to decide how to react.
+#[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
-    /// Errors returned by the Mercado Pago API.
-    #[error("API Error ({status}): {message}")]
-    ApiError {
-        message: String,
-        #[serde(default)]
-        error: String,
-        #[serde(default)]
-        status: u16,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        cause: Option<Vec<Cause>>,
-    },
+    /// A non-2xx response returned by the Mercado Pago API.
+    #[error("{0}")]
+    ApiError(Box<ApiError>),
 
-    /// Errors that occur within the SDK or during network requests.
+    /// Errors that occur within the SDK.
     #[error("Internal Error: {0}")]
-    #[serde(skip)]
     Internal(String),
 
-    /// Network-related errors.
+    /// The request could not be sent or the response could not be read.
     #[error("Network Error: {0}")]
-    #[serde(skip)]
-    Network(String),
+    Network(#[source] reqwest::Error),
+
+    /// The request timed out.
+    #[error("Timeout: {0}")]
+    Timeout(#[source] reqwest::Error),
 
-    /// Serialization/Deserialization errors.
+    /// Serialization/Deserialization errors. The underlying error (e.g., a
+    /// `serde_json::Error`) is kept as the `source()`.
     #[error("Serialization Error: {0}")]
-    #[serde(skip)]
-    Serialization(String),
+    Serialization(#[source] BoxError),
+
+    /// The circuit breaker for `group` is open; the request was not sent.
+    #[error("Circuit open for {group} endpoints, retry in {retry_after:?}")]
+    CircuitOpen {
+        group: EndpointGroup,
+        retry_after: Duration,
+    },
+}
+
+/// An error response returned by the Mercado Pago API.
+///
+/// Its `Debug` output masks personal data in `body`; see [`redact`].
+#[derive(Clone, PartialEq)]
+pub struct ApiError {
+    /// HTTP status code of the response.
+    pub status: u16,
+    /// Human-readable message, or the raw body if it was not a JSON error.
+    pub message: String,
+    /// Machine-readable error code (e.g., `"bad_request"`). Empty if absent.
+    pub error: String,
+    /// Specific causes, such as the fields that failed validation.
+    pub cause: Vec<Cause>,
+    /// Value of the `x-request-id` response header, to quote to support.
+    pub request_id: Option<String>,
+    /// Delay requested by the `Retry-After` header, if any.
+    pub retry_after: Option<Duration>,
+    /// Raw response body.
+    pub body: String,
+}
+
+impl fmt::Debug for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiError")
+            .field("status", &self.status)
+            .field("message", &self.message)
+            .field("error", &self.error)
+            .field("cause", &self.cause)
+            .field("request_id", &self.request_id)
+            .field("retry_after", &self.retry_after)
+            .field("body", &redact::redact_body(&self.body))
+            .finish()
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API Error ({}): {}", self.status, self.message)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, " [request id: {}]", request_id)?;
+        }
+        Ok(())
+    }
 }
 
 /// Represents a specific cause of an API error.
@@ -47,18 +107,165 @@ pub struct Cause {
     pub data: Option<serde_json::Value>,
 }
 
+/// The category of an [`Error`](enum@Error), as returned by [`Error::kind`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// `401`: the access token is missing, invalid or expired.
+    Unauthorized,
+    /// `403`: the token is not allowed to perform the operation.
+    Forbidden,
+    /// `404`: the resource does not exist.
+    NotFound,
+    /// `400` or `422`: the request was rejected, usually with field-level causes.
+    Validation { causes: Vec<Cause> },
+    /// `409`: the request conflicts with the current state of the resource.
+    Conflict,
+    /// `429`: too many requests, still rejected after the client's retries.
+    RateLimited { retry_after: Option<Duration> },
+    /// `5xx`: the API failed to handle the request.
+    Server,
+    /// The request timed out.
+    Timeout,
+    /// The request could not be sent or the response could not be read.
+    Network,
+    /// A request or response body could not be (de)serialized.
+    Serialization,
+    /// The circuit breaker rejected the request without sending it.
+    CircuitOpen { retry_after: Duration },
+    /// An error within the SDK, such as an invalid argument.
+    Internal,
+    /// Any other API error status.
+    Other,
+}
+
+impl Error {
+    /// Returns the category of this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::ApiError(api) => match api.status {
+                401 => ErrorKind::Unauthorized,
+                403 => ErrorKind::Forbidden,
+                404 => ErrorKind::NotFound,
+                400 | 422 => ErrorKind::Validation {
+                    causes: api.cause.clone(),
+                },
+                409 => ErrorKind::Conflict,
+                429 => ErrorKind::RateLimited {
+                    retry_after: api.retry_after,
+                },
+                500..=599 => ErrorKind::Server,
+                _ => ErrorKind::Other,
+            },
+            Error::Internal(_) => ErrorKind::Internal,
+            Error::Network(_) => ErrorKind::Network,
+            Error::Timeout(_) => ErrorKind::Timeout,
+            Error::Serialization(_) => ErrorKind::Serialization,
+            Error::CircuitOpen { retry_after, .. } => ErrorKind::CircuitOpen {
+                retry_after: *retry_after,
+            },
+        }
+    }
+
+    /// Returns `true` if repeating the same request may succeed: network
+    /// failures, timeouts, `429` and `5xx` responses, and requests rejected by
+    /// an open circuit breaker (after its `retry_after`).
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Network(_) | Error::Timeout(_) | Error::CircuitOpen { .. } => true,
+            Error::ApiError(api) => api.status == 429 || api.status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` for `4xx` API responses, which won't succeed unless the
+    /// request changes.
+    pub fn is_client_error(&self) -> bool {
+        matches!(self, Error::ApiError(api) if (400..500).contains(&api.status))
+    }
+
+    /// Returns the HTTP status code, if the error comes from an API response.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::ApiError(api) => Some(api.status),
+            Error::Network(e) | Error::Timeout(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Returns the `x-request-id` of the failed response, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::ApiError(api) => api.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Builds an [`Error::ApiError`] from a non-2xx response.
+    ///
+    /// The body is parsed leniently: a non-JSON body is kept as the message.
+    pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap, body: String) -> Self {
+        let parsed = serde_json::from_str::<ErrorBody>(&body).ok();
+        let (message, error, cause) = match parsed {
+            Some(parsed) => (
+                parsed.message.unwrap_or_else(|| status.to_string()),
+                parsed.error.unwrap_or_default(),
+                parsed.cause.map(causes_from_value).unwrap_or_default(),
+            ),
+            None if body.trim().is_empty() => (status.to_string(), String::new(), Vec::new()),
+            None => (body.clone(), String::new(), Vec::new()),
+        };
+
+        Error::ApiError(Box::new(ApiError {
+            status: status.as_u16(),
+            message,
+            error,
+            cause,
+            request_id: header_str(headers, "x-request-id").map(str::to_string),
+            retry_after: header_str(headers, "retry-after").and_then(parse_retry_after),
+            body,
+        }))
+    }
+}
+
+/// The JSON error body returned by the API.
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: Option<String>,
+    error: Option<String>,
+    cause: Option<serde_json::Value>,
+}
+
+/// `cause` is usually an array, but some endpoints return a single object.
+fn causes_from_value(value: serde_json::Value) -> Vec<Cause> {
+    match value {
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .filter_map(|item| serde_json::from_value(item).ok())
+            .collect(),
+        serde_json::Value::Object(_) => serde_json::from_value(value).into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Self {
         if err.is_decode() {
-            Error::Serialization(err.to_string())
+            Error::Serialization(Box::new(err))
+        } else if err.is_timeout() {
+            Error::Timeout(err)
         } else {
-            Error::Network(err.to_string())
+            Error::Network(err)
         }
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(err: serde_json::Error) -> Self {
-        Error::Serialization(err.to_string())
+        Error::Serialization(Box::new(err))
     }
 }
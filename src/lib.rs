@@ -15,9 +15,22 @@
 //! }
 //! ```
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod credentials;
+pub mod emvco;
 pub mod error;
 pub mod models;
+pub mod oauth;
+#[cfg(feature = "qr-render")]
+pub mod qr;
+pub mod reconcile;
+pub mod redact;
+pub mod reports;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod wait;
 
 pub use client::MercadoPagoClient;
-pub use error::Error;
+pub use error::{Error, ErrorKind};
@@ -0,0 +1,83 @@
+//! Models for the Chargebacks API.
+use serde::{Deserialize, Serialize};
+
+/// A chargeback (dispute) opened by the buyer's card issuer.
+///
+/// See the [official documentation](https://www.mercadopago.com.ar/developers/es/reference/chargebacks/_chargebacks_id/get) for more details.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chargeback {
+    /// Unique identifier of the chargeback.
+    pub id: String,
+    /// IDs of the disputed payments.
+    #[serde(default)]
+    pub payments: Vec<u64>,
+    /// Disputed amount.
+    pub amount: f64,
+    /// Currency of the amount (e.g., "ARS", "BRL").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Whether Mercado Pago's chargeback protection covers the seller.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage_applied: Option<bool>,
+    /// Whether the payment is eligible for coverage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage_elegible: Option<bool>,
+    /// Whether the seller must upload documentation to dispute the chargeback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation_required: Option<bool>,
+    /// Status of the documentation (e.g., "pending", "valid", "invalid").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation_status: Option<String>,
+    /// Documents uploaded so far.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Vec<ChargebackDocument>>,
+    /// Deadline to upload documentation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_documentation_deadline: Option<String>,
+    /// Date the chargeback was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    /// Date the chargeback was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_last_updated: Option<String>,
+    /// Whether the chargeback belongs to live mode (vs sandbox).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_mode: Option<bool>,
+}
+
+/// A document attached to a [`Chargeback`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChargebackDocument {
+    /// Type of document (e.g., "image/png").
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc_type: Option<String>,
+    /// URL of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Description of the document.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A file to upload as chargeback documentation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentationFile {
+    /// Name of the file (e.g., "invoice.pdf").
+    pub file_name: String,
+    /// MIME type of the file (e.g., "application/pdf", "image/jpeg").
+    pub content_type: String,
+    /// Contents of the file.
+    pub content: Vec<u8>,
+}
+
+impl DocumentationFile {
+    /// Creates a file to upload.
+    pub fn new(file_name: &str, content_type: &str, content: Vec<u8>) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            content_type: content_type.to_string(),
+            content,
+        }
+    }
+}
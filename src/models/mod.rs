@@ -1,5 +1,11 @@
+pub mod chargebacks;
 pub mod common;
 pub mod instore;
+pub mod oauth;
+pub mod orders;
 pub mod payments;
+pub mod point;
 pub mod preferences;
 pub mod refunds;
+pub mod reports;
+pub mod users;
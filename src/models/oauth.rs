@@ -0,0 +1,147 @@
+//! Models for the OAuth API.
+use crate::credentials::{ExposeSecret, SecretString};
+use crate::redact::{Mask, masked};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Request body for `POST /oauth/token`.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OAuthTokenRequest {
+    /// Application ID.
+    pub client_id: String,
+    /// Application secret.
+    pub client_secret: String,
+    /// Either `authorization_code` or `refresh_token`.
+    pub grant_type: String,
+    /// Authorization code received on the redirect URI.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Redirect URI used to obtain the authorization code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_uri: Option<String>,
+    /// PKCE code verifier, if a code challenge was sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_verifier: Option<String>,
+    /// Refresh token, for the `refresh_token` grant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+}
+
+impl fmt::Debug for OAuthTokenRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthTokenRequest")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &masked(&self.client_secret, Mask::Secret))
+            .field("grant_type", &self.grant_type)
+            .field("code", &masked(&self.code, Mask::Secret))
+            .field("redirect_uri", &self.redirect_uri)
+            .field("code_verifier", &masked(&self.code_verifier, Mask::Secret))
+            .field("refresh_token", &masked(&self.refresh_token, Mask::Secret))
+            .finish()
+    }
+}
+
+/// Access token issued for a seller through OAuth.
+///
+/// The access and refresh tokens are held as [`SecretString`]; they are
+/// serialized in clear, e.g., to store the token, but never printed by `Debug`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OAuthToken {
+    /// Access token to act on behalf of the seller.
+    #[serde(with = "secret")]
+    pub access_token: SecretString,
+    /// Token used to obtain a new access token. Each refresh token can be used only once.
+    #[serde(
+        default,
+        with = "optional_secret",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub refresh_token: Option<SecretString>,
+    /// Lifetime of the access token, in seconds.
+    pub expires_in: u64,
+    /// ID of the seller (collector) that granted access.
+    pub user_id: u64,
+    /// Granted scopes (e.g., "offline_access read write").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Type of the token (e.g., "Bearer").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    /// Public key of the seller, for client-side card tokenization.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    /// Whether the token is for production (vs sandbox).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_mode: Option<bool>,
+}
+
+impl fmt::Debug for OAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthToken")
+            .field("access_token", &masked(&self.access_token, Mask::Secret))
+            .field("refresh_token", &masked(&self.refresh_token, Mask::Secret))
+            .field("expires_in", &self.expires_in)
+            .field("user_id", &self.user_id)
+            .field("scope", &self.scope)
+            .field("token_type", &self.token_type)
+            .field("public_key", &self.public_key)
+            .field("live_mode", &self.live_mode)
+            .finish()
+    }
+}
+
+impl PartialEq for OAuthToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.access_token.expose_secret() == other.access_token.expose_secret()
+            && self.refresh_token.as_ref().map(ExposeSecret::expose_secret)
+                == other
+                    .refresh_token
+                    .as_ref()
+                    .map(ExposeSecret::expose_secret)
+            && self.expires_in == other.expires_in
+            && self.user_id == other.user_id
+            && self.scope == other.scope
+            && self.token_type == other.token_type
+            && self.public_key == other.public_key
+            && self.live_mode == other.live_mode
+    }
+}
+
+/// (De)serializes a [`SecretString`] as a plain string.
+mod secret {
+    use super::*;
+
+    pub(super) fn serialize<S: Serializer>(
+        secret: &SecretString,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(secret.expose_secret())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<SecretString, D::Error> {
+        String::deserialize(deserializer).map(SecretString::from)
+    }
+}
+
+/// (De)serializes an optional [`SecretString`] as a plain string or `null`.
+mod optional_secret {
+    use super::*;
+
+    pub(super) fn serialize<S: Serializer>(
+        secret: &Option<SecretString>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        secret
+            .as_ref()
+            .map(ExposeSecret::expose_secret)
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<SecretString>, D::Error> {
+        Option::<String>::deserialize(deserializer).map(|s| s.map(SecretString::from))
+    }
+}
@@ -0,0 +1,261 @@
+//! Models for the Orders API (`/v1/orders`).
+//!
+//! An order groups the transactions of a sale, whether it is charged online
+//! with a card, with a QR code or on a Point terminal. Amounts are decimal
+//! strings (e.g., `"150.00"`), as the API expects them.
+use super::common::Paging;
+use super::payments::Identification;
+use crate::redact::{Mask, masked};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Request to create an order.
+///
+/// See the [official documentation](https://www.mercadopago.com.ar/developers/es/reference/orders/online-payments/create/post) for more details.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderRequest {
+    /// Channel of the order.
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    /// Your identifier of the order.
+    pub external_reference: String,
+    /// Total amount, as a decimal string.
+    pub total_amount: String,
+    /// Whether the order is processed on creation or by
+    /// [`process_order`](crate::MercadoPagoClient::process_order).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_mode: Option<ProcessingMode>,
+    /// Capture mode of card payments ("automatic" or "manual").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_mode: Option<String>,
+    /// Description of the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Payer of the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payer: Option<OrderPayer>,
+    /// Payments to process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Transactions>,
+    /// QR or Point settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<OrderConfig>,
+    /// Expiration of the order, as an ISO 8601 duration (e.g., "PT15M").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<String>,
+}
+
+/// Channel of an order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// Online card payments.
+    #[default]
+    Online,
+    /// In-person payment with a QR code.
+    Qr,
+    /// In-person payment on a Point terminal.
+    Point,
+}
+
+/// When an order is processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingMode {
+    /// The transactions are processed when the order is created.
+    Automatic,
+    /// The order is created and processed later, in a separate call.
+    Manual,
+}
+
+/// Payer of an order.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderPayer {
+    /// Email of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// First name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Last name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Identification of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identification: Option<Identification>,
+}
+
+impl fmt::Debug for OrderPayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderPayer")
+            .field("email", &masked(&self.email, Mask::Email))
+            .field("first_name", &masked(&self.first_name, Mask::Name))
+            .field("last_name", &masked(&self.last_name, Mask::Name))
+            .field("identification", &self.identification)
+            .finish()
+    }
+}
+
+/// QR and Point settings of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderConfig {
+    /// QR settings, for [`OrderType::Qr`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qr: Option<QrConfig>,
+    /// Point settings, for [`OrderType::Point`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub point: Option<PointConfig>,
+}
+
+/// QR settings of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QrConfig {
+    /// External ID of the POS that shows the QR code.
+    pub external_pos_id: String,
+    /// QR mode ("static", "dynamic" or "hybrid").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+/// Point settings of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointConfig {
+    /// ID of the terminal that charges the order.
+    pub terminal_id: String,
+    /// Whether the terminal prints a receipt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub print_on_terminal: Option<String>,
+}
+
+/// Transactions of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Transactions {
+    /// Payments of the order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payments: Vec<Payment>,
+    /// Refunds of the order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub refunds: Vec<Refund>,
+}
+
+/// A payment transaction of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Payment {
+    /// Identifier of the transaction (set by the API).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Amount, as a decimal string.
+    pub amount: String,
+    /// Status of the transaction (e.g., "processed", "failed").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Detail of the status (e.g., "accredited").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    /// ID of the payment in the Payments API, once processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_id: Option<String>,
+    /// Payment method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethod>,
+}
+
+/// Payment method of an order payment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentMethod {
+    /// Identifier of the payment method (e.g., "visa", "pix").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Type of payment method (e.g., "credit_card", "bank_transfer").
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method_type: Option<String>,
+    /// Card token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Number of installments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments: Option<u32>,
+    /// Text shown on the buyer's card statement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+}
+
+/// A refund transaction of an order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Refund {
+    /// Identifier of the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// ID of the refunded payment transaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+    /// Refunded amount, as a decimal string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Status of the refund.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// An order, as returned by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    /// Unique identifier of the order (e.g., "ORD01J...").
+    pub id: String,
+    /// Channel of the order.
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_type: Option<OrderType>,
+    /// Your identifier of the order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reference: Option<String>,
+    /// Total amount, as a decimal string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_amount: Option<String>,
+    /// Processing mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_mode: Option<ProcessingMode>,
+    /// Status of the order (e.g., "created", "processed", "action_required",
+    /// "canceled", "refunded", "failed").
+    pub status: String,
+    /// Detail of the status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_detail: Option<String>,
+    /// Transactions of the order.
+    #[serde(default)]
+    pub transactions: Transactions,
+    /// Date the order was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<String>,
+    /// Date the order was last updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_date: Option<String>,
+}
+
+/// Request to refund an order, partially or in full.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefundOrderRequest {
+    /// Payment transactions to refund. Refunds the whole order when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<RefundTransaction>,
+}
+
+/// A payment transaction to refund.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefundTransaction {
+    /// ID of the payment transaction.
+    pub id: String,
+    /// Amount to refund, as a decimal string.
+    pub amount: String,
+}
+
+/// Response of the order search endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderSearchResponse {
+    /// Orders in the current page.
+    pub data: Vec<Order>,
+    /// Pagination metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<Paging>,
+}
@@ -1,5 +1,10 @@
 //! Models for the Payments API.
+use crate::error::Error;
+use crate::redact::{Mask, masked};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub use super::preferences::Phone;
 
 /// Represents a request to create a payment.
 ///
@@ -27,20 +32,339 @@ pub struct PaymentRequest {
     /// Email of the payer (if not in payer struct).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notification_url: Option<String>,
+    /// Fee collected by the marketplace, deducted from the seller's proceeds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee: Option<f64>,
+    /// User ID of the platform that integrates the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sponsor_id: Option<u64>,
+    /// Text shown on the buyer's card statement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<String>,
+    /// If `true`, the payment is either approved or rejected, never left pending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub binary_mode: Option<bool>,
+    /// Arbitrary key/value data returned with the payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// Extra information used by fraud prevention (items, payer, shipments).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<AdditionalInfo>,
+    /// Whether 3-D Secure authentication is requested for card payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_d_secure_mode: Option<ThreeDSecureMode>,
+    /// Expiration date of PIX and ticket payments, in ISO 8601 format
+    /// (e.g., "2026-10-20T23:59:59.000-03:00").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_expiration: Option<String>,
 }
 
-/// Information about the payer of the payment.
+/// Payment methods that need the payer's full name, identification and
+/// address (Brazilian boleto and lottery payments).
+pub(crate) const TICKET_METHODS_WITH_ADDRESS: &[&str] = &["bolbradesco", "pec"];
+
+impl PaymentRequest {
+    /// Creates a PIX payment request.
+    ///
+    /// The QR code and copy-and-paste code of the created payment are returned
+    /// in [`PaymentResponse::pix_qr_code`] and [`PaymentResponse::pix_qr_code_base64`].
+    pub fn pix(transaction_amount: f64, payer: Payer) -> Self {
+        Self {
+            transaction_amount,
+            payment_method_id: "pix".to_string(),
+            payer,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a cash ticket payment request (e.g., "bolbradesco", "pec",
+    /// "rapipago", "pagofacil", "oxxo").
+    ///
+    /// The voucher link of the created payment is returned in
+    /// [`PaymentResponse::ticket_url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Internal`] if `payment_method_id` is a boleto method
+    /// ("bolbradesco", "pec") and the payer lacks first name, last name,
+    /// identification or a complete address (zip code, street name and
+    /// number, neighborhood, city and federal unit).
+    pub fn ticket(
+        transaction_amount: f64,
+        payment_method_id: &str,
+        payer: Payer,
+    ) -> Result<Self, Error> {
+        if TICKET_METHODS_WITH_ADDRESS.contains(&payment_method_id) {
+            let missing = payer.missing_boleto_fields();
+            if !missing.is_empty() {
+                return Err(Error::Internal(format!(
+                    "Payer is missing fields required by {}: {}",
+                    payment_method_id,
+                    missing.join(", ")
+                )));
+            }
+        }
+
+        Ok(Self {
+            transaction_amount,
+            payment_method_id: payment_method_id.to_string(),
+            payer,
+            ..Default::default()
+        })
+    }
+
+    /// Sets the expiration date of a PIX or ticket payment.
+    pub fn with_date_of_expiration(mut self, date: &str) -> Self {
+        self.date_of_expiration = Some(date.to_string());
+        self
+    }
+
+    /// Sets the marketplace fee to a fixed amount.
+    pub fn with_application_fee(mut self, fee: f64) -> Self {
+        self.application_fee = Some(fee);
+        self
+    }
+
+    /// Sets the marketplace fee to a percentage of the transaction amount,
+    /// rounded to cents.
+    pub fn with_application_fee_percentage(mut self, percentage: f64) -> Self {
+        let fee = self.transaction_amount * percentage / 100.0;
+        self.application_fee = Some((fee * 100.0).round() / 100.0);
+        self
+    }
+}
+
+/// 3-D Secure mode of a card payment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDSecureMode {
+    /// 3-D Secure is never requested.
+    NotSupported,
+    /// The issuer decides whether a challenge is needed.
+    Optional,
+    /// Every payment goes through 3-D Secure.
+    Mandatory,
+}
+
+/// Additional information about a payment, used for fraud prevention.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdditionalInfo {
+    /// Items being paid for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<AdditionalInfoItem>>,
+    /// Additional payer data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payer: Option<AdditionalInfoPayer>,
+    /// Shipping data.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipments: Option<Shipments>,
+    /// IP address of the buyer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+}
+
+/// An item in [`AdditionalInfo`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdditionalInfoItem {
+    /// Item identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Title of the item.
+    pub title: String,
+    /// Description of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// URL of the item image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub picture_url: Option<String>,
+    /// Category of the item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<String>,
+    /// Quantity of the item.
+    pub quantity: i32,
+    /// Unit price of the item.
+    pub unit_price: f64,
+}
+
+/// Payer data in [`AdditionalInfo`].
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdditionalInfoPayer {
+    /// First name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Last name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Phone of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<Phone>,
+    /// Address of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /// Date the payer registered on the seller's site.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration_date: Option<String>,
+}
+
+impl fmt::Debug for AdditionalInfoPayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AdditionalInfoPayer")
+            .field("first_name", &masked(&self.first_name, Mask::Name))
+            .field("last_name", &masked(&self.last_name, Mask::Name))
+            .field("phone", &self.phone)
+            .field("address", &self.address)
+            .field("registration_date", &self.registration_date)
+            .finish()
+    }
+}
+
+/// Shipping data in [`AdditionalInfo`].
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Shipments {
+    /// Address the order is shipped to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_address: Option<ReceiverAddress>,
+}
+
+/// Shipping address in [`Shipments`].
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReceiverAddress {
+    /// Postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip_code: Option<String>,
+    /// State or province.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_name: Option<String>,
+    /// City.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+    /// Street name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_name: Option<String>,
+    /// Street number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_number: Option<String>,
+    /// Floor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor: Option<String>,
+    /// Apartment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apartment: Option<String>,
+}
+
+impl fmt::Debug for ReceiverAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReceiverAddress")
+            .field("zip_code", &self.zip_code)
+            .field("state_name", &self.state_name)
+            .field("city_name", &self.city_name)
+            .field("street_name", &masked(&self.street_name, Mask::Street))
+            .field("street_number", &masked(&self.street_number, Mask::Street))
+            .field("floor", &self.floor)
+            .field("apartment", &self.apartment)
+            .finish()
+    }
+}
+
+/// Postal address of a payer.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    /// Postal code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip_code: Option<String>,
+    /// Street name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_name: Option<String>,
+    /// Street number.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_number: Option<String>,
+    /// Neighborhood (bairro).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub neighborhood: Option<String>,
+    /// City.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// State code (e.g., "SP").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federal_unit: Option<String>,
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Address")
+            .field("zip_code", &self.zip_code)
+            .field("street_name", &masked(&self.street_name, Mask::Street))
+            .field("street_number", &masked(&self.street_number, Mask::Street))
+            .field("neighborhood", &self.neighborhood)
+            .field("city", &self.city)
+            .field("federal_unit", &self.federal_unit)
+            .finish()
+    }
+}
+
+/// Information about the payer of the payment.
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Payer {
     /// Email of the payer.
     pub email: String,
     /// Identification of the payer (DNI, etc.).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identification: Option<Identification>,
+    /// First name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Last name of the payer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Address of the payer, required by boleto payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+}
+
+impl fmt::Debug for Payer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Payer")
+            .field("email", &masked(&self.email, Mask::Email))
+            .field("identification", &self.identification)
+            .field("first_name", &masked(&self.first_name, Mask::Name))
+            .field("last_name", &masked(&self.last_name, Mask::Name))
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl Payer {
+    pub(crate) fn missing_boleto_fields(&self) -> Vec<&'static str> {
+        fn filled(value: &Option<String>) -> bool {
+            value.as_deref().is_some_and(|v| !v.trim().is_empty())
+        }
+
+        let address = self.address.clone().unwrap_or_default();
+        [
+            ("first_name", filled(&self.first_name)),
+            ("last_name", filled(&self.last_name)),
+            (
+                "identification",
+                self.identification
+                    .as_ref()
+                    .is_some_and(|id| !id.number.trim().is_empty()),
+            ),
+            ("address.zip_code", filled(&address.zip_code)),
+            ("address.street_name", filled(&address.street_name)),
+            ("address.street_number", filled(&address.street_number)),
+            ("address.neighborhood", filled(&address.neighborhood)),
+            ("address.city", filled(&address.city)),
+            ("address.federal_unit", filled(&address.federal_unit)),
+        ]
+        .into_iter()
+        .filter(|(_, present)| !present)
+        .map(|(field, _)| field)
+        .collect()
+    }
 }
 
 /// Identification information for a payer.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Identification {
     /// Type of identification (e.g., DNI).
     #[serde(rename = "type")]
@@ -49,6 +373,15 @@ pub struct Identification {
     pub number: String,
 }
 
+impl fmt::Debug for Identification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Identification")
+            .field("id_type", &self.id_type)
+            .field("number", &masked(&self.number, Mask::Number))
+            .finish()
+    }
+}
+
 /// Represents the response from the Mercado Pago API after a payment operation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PaymentResponse {
@@ -103,13 +436,134 @@ pub struct PaymentResponse {
     /// Fees charged on this payment.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fee_details: Option<Vec<FeeDetail>>,
+    /// 3-D Secure challenge data, present when `status_detail` is
+    /// `"pending_challenge"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_ds_info: Option<ThreeDsInfo>,
+    /// Data to complete the payment outside the API (PIX QR code, ticket link).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub point_of_interaction: Option<PointOfInteraction>,
+    /// Amount breakdown and ticket link.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_details: Option<TransactionDetails>,
+    /// Expiration date of PIX and ticket payments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_of_expiration: Option<String>,
+}
+
+/// Where and how the buyer completes the payment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PointOfInteraction {
+    /// Type of point of interaction (e.g., "PIX").
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poi_type: Option<String>,
+    /// Data the buyer needs to pay.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_data: Option<TransactionData>,
+}
+
+/// PIX payment data in [`PointOfInteraction`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionData {
+    /// PIX copy-and-paste code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qr_code: Option<String>,
+    /// PIX QR code as a base64-encoded PNG.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qr_code_base64: Option<String>,
+    /// URL of the page showing the payment instructions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_url: Option<String>,
+}
+
+/// Amount breakdown of a payment.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionDetails {
+    /// URL of the ticket (boleto, Rapipago, OXXO voucher) to print or show.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_resource_url: Option<String>,
+    /// Barcode or reference number of the ticket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_reference_id: Option<String>,
+    /// Amount received after fees.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net_received_amount: Option<f64>,
+    /// Amount paid by the buyer, including fees and interest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_paid_amount: Option<f64>,
+    /// Amount of each installment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installment_amount: Option<f64>,
+}
+
+/// 3-D Secure challenge data of a card payment.
+///
+/// The buyer completes the challenge on the issuer's page by posting `creq`
+/// to `external_resource_url`, usually from an iframe rendered with
+/// [`challenge_form_html`](ThreeDsInfo::challenge_form_html).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThreeDsInfo {
+    /// URL of the issuer's challenge page.
+    pub external_resource_url: String,
+    /// Challenge request to post to the issuer.
+    pub creq: String,
+}
+
+impl ThreeDsInfo {
+    /// Returns an HTML document that posts the challenge request to the
+    /// issuer as soon as it loads.
+    ///
+    /// ```
+    /// use mercadopago_sdk::models::payments::ThreeDsInfo;
+    ///
+    /// let info = ThreeDsInfo {
+    ///     external_resource_url: "https://acs.example.com/challenge".to_string(),
+    ///     creq: "eyJ0aHJlZURTU2VydmVy".to_string(),
+    /// };
+    /// let html = info.challenge_form_html();
+    /// assert!(html.contains(r#"action="https://acs.example.com/challenge""#));
+    /// assert!(html.contains(r#"name="creq" value="eyJ0aHJlZURTU2VydmVy""#));
+    /// ```
+    pub fn challenge_form_html(&self) -> String {
+        format!(
+            concat!(
+                "<!DOCTYPE html>\n",
+                "<html>\n",
+                "<body onload=\"document.forms[0].submit()\">\n",
+                "<form method=\"POST\" action=\"{}\" enctype=\"application/x-www-form-urlencoded\">\n",
+                "<input type=\"hidden\" name=\"creq\" value=\"{}\">\n",
+                "<noscript><button type=\"submit\">Continue</button></noscript>\n",
+                "</form>\n",
+                "</body>\n",
+                "</html>\n"
+            ),
+            escape_html(&self.external_resource_url),
+            escape_html(&self.creq)
+        )
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 /// Payer information as returned in a payment response.
 ///
 /// Separate from [`Payer`] (used in requests) because the response includes
 /// additional fields like `id` and makes all fields optional.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PayerResponse {
     /// Unique identifier of the payer.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,6 +580,17 @@ pub struct PayerResponse {
     pub payer_type: Option<String>,
 }
 
+impl fmt::Debug for PayerResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayerResponse")
+            .field("id", &self.id)
+            .field("email", &masked(&self.email, Mask::Email))
+            .field("identification", &self.identification)
+            .field("payer_type", &self.payer_type)
+            .finish()
+    }
+}
+
 /// Fee charged on a payment.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FeeDetail {
@@ -140,3 +605,124 @@ pub struct FeeDetail {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fee_payer: Option<String>,
 }
+
+/// Lifecycle status of a payment, as reported in [`PaymentResponse::status`].
+///
+/// Unknown values returned by the API are preserved in [`PaymentStatus::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentStatus {
+    /// The payment is pending (e.g., waiting for the buyer to pay).
+    Pending,
+    /// The payment was approved and accredited.
+    Approved,
+    /// The payment was authorized but not captured yet.
+    Authorized,
+    /// The payment is being reviewed.
+    InProcess,
+    /// The payment is in dispute.
+    InMediation,
+    /// The payment was rejected.
+    Rejected,
+    /// The payment was cancelled or expired.
+    Cancelled,
+    /// The payment was refunded to the buyer.
+    Refunded,
+    /// A chargeback was applied to the buyer's card.
+    ChargedBack,
+    /// Any other status not known by this SDK version.
+    Other(String),
+}
+
+impl PaymentStatus {
+    /// Parses a status string as returned by the API.
+    pub fn parse(status: &str) -> Self {
+        match status {
+            "pending" => Self::Pending,
+            "approved" => Self::Approved,
+            "authorized" => Self::Authorized,
+            "in_process" => Self::InProcess,
+            "in_mediation" => Self::InMediation,
+            "rejected" => Self::Rejected,
+            "cancelled" => Self::Cancelled,
+            "refunded" => Self::Refunded,
+            "charged_back" => Self::ChargedBack,
+            other => Self::Other(other.to_string()),
+        }
+    }
+
+    /// Returns the status as sent by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Authorized => "authorized",
+            Self::InProcess => "in_process",
+            Self::InMediation => "in_mediation",
+            Self::Rejected => "rejected",
+            Self::Cancelled => "cancelled",
+            Self::Refunded => "refunded",
+            Self::ChargedBack => "charged_back",
+            Self::Other(other) => other,
+        }
+    }
+
+    /// Returns `true` if the payment will not change status without further action.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Self::Approved | Self::Rejected | Self::Cancelled | Self::Refunded | Self::ChargedBack
+        )
+    }
+}
+
+impl PaymentResponse {
+    /// Returns the typed [`PaymentStatus`] of this payment.
+    pub fn payment_status(&self) -> PaymentStatus {
+        PaymentStatus::parse(&self.status)
+    }
+
+    /// Returns the 3-D Secure challenge the buyer must complete before the
+    /// payment can be approved, if any.
+    pub fn challenge(&self) -> Option<&ThreeDsInfo> {
+        match self.status_detail.as_deref() {
+            Some("pending_challenge") => self.three_ds_info.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the PIX copy-and-paste code.
+    pub fn pix_qr_code(&self) -> Option<&str> {
+        self.transaction_data()?.qr_code.as_deref()
+    }
+
+    /// Returns the PIX QR code as a base64-encoded PNG.
+    pub fn pix_qr_code_base64(&self) -> Option<&str> {
+        self.transaction_data()?.qr_code_base64.as_deref()
+    }
+
+    /// Returns the URL of the ticket or payment instructions page, if any.
+    pub fn ticket_url(&self) -> Option<&str> {
+        self.transaction_details
+            .as_ref()
+            .and_then(|details| details.external_resource_url.as_deref())
+            .or_else(|| self.transaction_data()?.ticket_url.as_deref())
+    }
+
+    fn transaction_data(&self) -> Option<&TransactionData> {
+        self.point_of_interaction
+            .as_ref()?
+            .transaction_data
+            .as_ref()
+    }
+
+    /// Returns the sum of the fees of `fee_type` (e.g., "application_fee",
+    /// "mercadopago_fee") charged on this payment.
+    pub fn fee_amount(&self, fee_type: &str) -> f64 {
+        self.fee_details
+            .iter()
+            .flatten()
+            .filter(|fee| fee.fee_type.as_deref() == Some(fee_type))
+            .filter_map(|fee| fee.amount)
+            .sum()
+    }
+}
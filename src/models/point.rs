@@ -0,0 +1,151 @@
+//! Models for the Point (card terminals) integration API.
+use super::common::Paging;
+use serde::{Deserialize, Serialize};
+
+/// A Point terminal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PointDevice {
+    /// Identifier of the device (e.g., "PAX_A910__SMARTPOS1234567890").
+    pub id: String,
+    /// Identifier of the POS the device is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_id: Option<u64>,
+    /// Identifier of the store the device is assigned to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_id: Option<String>,
+    /// External identifier of the POS.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_pos_id: Option<String>,
+    /// Current operating mode.
+    pub operating_mode: OperatingMode,
+}
+
+/// Operating mode of a Point terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperatingMode {
+    /// Integrated with a point-of-sale system: charges come from payment intents.
+    Pdv,
+    /// The terminal is operated manually.
+    Standalone,
+}
+
+/// Response of the device list endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PointDeviceList {
+    /// Devices matching the filters.
+    pub devices: Vec<PointDevice>,
+    /// Pagination metadata.
+    pub paging: Paging,
+}
+
+/// Request to change the operating mode of a device.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperatingModeRequest {
+    /// New operating mode.
+    pub operating_mode: OperatingMode,
+}
+
+/// Response of the operating mode change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OperatingModeResponse {
+    /// Operating mode the device will use after its next restart.
+    pub operating_mode: OperatingMode,
+}
+
+/// Request to charge an amount on a Point terminal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentRequest {
+    /// Amount to charge, in cents (e.g., `1500` charges 15.00).
+    pub amount: u64,
+    /// Description of the charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Payment options (installments, card type).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<PaymentIntentPayment>,
+    /// Additional information (external reference, printing).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<PaymentIntentAdditionalInfo>,
+}
+
+/// Payment options of a payment intent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentPayment {
+    /// ID of the payment created when the intent is processed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Number of installments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments: Option<u32>,
+    /// Card type (e.g., "credit_card", "debit_card").
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_type: Option<String>,
+    /// Who pays the installment cost ("seller" or "buyer").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installments_cost: Option<String>,
+}
+
+/// Additional information of a payment intent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentAdditionalInfo {
+    /// External reference copied to the resulting payment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_reference: Option<String>,
+    /// Whether the terminal prints a receipt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub print_on_terminal: Option<bool>,
+}
+
+/// A payment intent sent to a Point terminal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntent {
+    /// Unique identifier of the intent.
+    pub id: String,
+    /// Device the intent was sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+    /// Amount to charge, in cents.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    /// Description of the charge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// State of the intent (e.g., "OPEN", "ON_TERMINAL", "PROCESSING",
+    /// "PROCESSED", "FINISHED", "CANCELED", "ERROR").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Payment options and, once processed, the resulting payment ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<PaymentIntentPayment>,
+    /// Additional information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<PaymentIntentAdditionalInfo>,
+}
+
+impl PaymentIntent {
+    /// Returns the ID of the payment created by the terminal, once processed.
+    pub fn payment_id(&self) -> Option<u64> {
+        self.payment.as_ref()?.id
+    }
+}
+
+/// State changes of payment intents, returned by the events endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentEvents {
+    /// Events in the requested date range.
+    pub events: Vec<PaymentIntentEvent>,
+}
+
+/// A state change of a payment intent.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentIntentEvent {
+    /// ID of the payment intent.
+    pub payment_intent_id: String,
+    /// New state of the intent.
+    pub status: String,
+    /// Date of the change.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<String>,
+}
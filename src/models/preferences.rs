@@ -1,5 +1,7 @@
 //! Models for the Preferences API (Checkout Pro).
+use crate::redact::{Mask, masked};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a request to create a payment preference (Checkout Pro).
 ///
@@ -49,7 +51,7 @@ pub struct PreferenceItem {
 }
 
 /// Information about the payer for a preference.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PreferencePayer {
     /// Name of the payer.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,8 +70,20 @@ pub struct PreferencePayer {
     pub identification: Option<Identification>,
 }
 
+impl fmt::Debug for PreferencePayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreferencePayer")
+            .field("name", &masked(&self.name, Mask::Name))
+            .field("surname", &masked(&self.surname, Mask::Name))
+            .field("email", &masked(&self.email, Mask::Email))
+            .field("phone", &self.phone)
+            .field("identification", &self.identification)
+            .finish()
+    }
+}
+
 /// Phone number of the payer.
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Phone {
     /// Area code of the phone number.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -79,9 +93,18 @@ pub struct Phone {
     pub number: Option<String>,
 }
 
+impl fmt::Debug for Phone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Phone")
+            .field("area_code", &self.area_code)
+            .field("number", &masked(&self.number, Mask::Number))
+            .finish()
+    }
+}
+
 /// Identification document of the payer.
 // TODO(v0.2): unify with payments::Identification (this uses Option<String>, payments uses String)
-#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Identification {
     /// Type of identification (e.g., DNI, CPF).
     #[serde(rename = "type")]
@@ -92,6 +115,15 @@ pub struct Identification {
     pub number: Option<String>,
 }
 
+impl fmt::Debug for Identification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Identification")
+            .field("id_type", &self.id_type)
+            .field("number", &masked(&self.number, Mask::Number))
+            .finish()
+    }
+}
+
 /// Back URLs for redirecting the user.
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct BackUrls {
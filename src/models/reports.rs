@@ -0,0 +1,108 @@
+//! Models for the settlement and release reports API.
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a report: columns, format and automatic schedule.
+///
+/// The same structure is used by settlement and release reports; fields that
+/// do not apply to a report type are ignored by the API.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportConfig {
+    /// Prefix of the generated file names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name_prefix: Option<String>,
+    /// Columns included in the report, in order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<ReportColumn>>,
+    /// Column separator (`","` or `";"`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separator: Option<String>,
+    /// Timezone of the dates in the report (e.g., "GMT-03").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_timezone: Option<String>,
+    /// Language of the column headers (e.g., "en", "es", "pt").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_translation: Option<String>,
+    /// Emails notified when a report is generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_email_list: Option<Vec<String>>,
+    /// Automatic generation schedule.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<ReportFrequency>,
+    /// Whether automatic generation is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled: Option<bool>,
+    /// Include fee forecasts (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_fee_prevision: Option<bool>,
+    /// Include cancelled chargebacks (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_chargeback_cancel: Option<bool>,
+    /// Show coupons as separate rows (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon_detailed: Option<bool>,
+    /// Include withdrawals (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_withdraw: Option<bool>,
+    /// Show shipping costs as separate rows (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_detail: Option<bool>,
+    /// Show refunds as separate rows (settlement report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_detailed: Option<bool>,
+    /// Generate a report after every withdrawal (release report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_after_withdrawal: Option<bool>,
+    /// Include withdrawals at the end of the report (release report).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_withdrawal_at_end: Option<bool>,
+}
+
+/// A column of a report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportColumn {
+    /// Column name (e.g., "SOURCE_ID").
+    pub key: String,
+}
+
+/// Automatic generation schedule of a report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFrequency {
+    /// Period type ("daily", "weekly" or "monthly").
+    #[serde(rename = "type")]
+    pub frequency_type: String,
+    /// Day of the period (day of the week or month; ignored for daily reports).
+    pub value: u32,
+    /// Hour of the day the report is generated.
+    pub hour: u32,
+}
+
+/// Request to generate a report for a date range.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportRequest {
+    /// Start of the range, in ISO 8601 format (e.g., "2026-10-01T00:00:00Z").
+    pub begin_date: String,
+    /// End of the range, in ISO 8601 format.
+    pub end_date: String,
+}
+
+/// A generated report file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFile {
+    /// Identifier of the report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name of the file, used to download it.
+    pub file_name: String,
+    /// Start of the covered range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin_date: Option<String>,
+    /// End of the covered range.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// How the report was generated (e.g., "manual", "schedule").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    /// Date the report was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+}
@@ -0,0 +1,84 @@
+//! Models for the Users API.
+use crate::redact::{Mask, masked};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Request to create a test user.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestUserRequest {
+    /// Site of the user (e.g., "MLA", "MLB", "MLM").
+    pub site_id: String,
+    /// Description to tell test users apart (e.g., "buyer", "seller").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// A test user, returned once on creation.
+///
+/// Store the credentials: the password cannot be retrieved later.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestUser {
+    /// User ID (the collector ID of a test seller).
+    pub id: u64,
+    /// Nickname, used to log in.
+    pub nickname: String,
+    /// Password, used to log in.
+    pub password: String,
+    /// Status of the user on the site (e.g., "active").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_status: Option<String>,
+    /// Email of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl fmt::Debug for TestUser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TestUser")
+            .field("id", &self.id)
+            .field("nickname", &self.nickname)
+            .field("password", &masked(&self.password, Mask::Secret))
+            .field("site_status", &self.site_status)
+            .field("email", &masked(&self.email, Mask::Email))
+            .finish()
+    }
+}
+
+/// The user that owns the access token.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    /// User ID, used as collector ID by the store, POS and QR APIs.
+    pub id: u64,
+    /// Nickname of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+    /// Email of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// First name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /// Last name of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Site of the user (e.g., "MLA").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<String>,
+    /// Country of the user (e.g., "AR").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_id: Option<String>,
+}
+
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("id", &self.id)
+            .field("nickname", &self.nickname)
+            .field("email", &masked(&self.email, Mask::Email))
+            .field("first_name", &masked(&self.first_name, Mask::Name))
+            .field("last_name", &masked(&self.last_name, Mask::Name))
+            .field("site_id", &self.site_id)
+            .field("country_id", &self.country_id)
+            .finish()
+    }
+}
@@ -0,0 +1,348 @@
+//! OAuth authorization code flow for marketplace sellers.
+//!
+//! A marketplace sends each seller to [`OAuthClient::authorization_url`], receives
+//! an authorization code on its redirect URI and exchanges it with
+//! [`OAuthClient::exchange_code`] for an [`OAuthToken`]. Wrapping that token in an
+//! [`OAuthSession`] and passing it to
+//! [`MercadoPagoClientBuilder::oauth_session`](crate::client::MercadoPagoClientBuilder::oauth_session)
+//! gives a client that refreshes the token before it expires.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::oauth::{OAuthClient, OAuthSession, Pkce};
+//!
+//! # async fn run() -> Result<(), mercadopago_sdk::Error> {
+//! let platform = MercadoPagoClient::builder("PLATFORM_ACCESS_TOKEN").build()?;
+//! let oauth = OAuthClient::new(platform, "APP_ID", "CLIENT_SECRET", "https://example.com/callback");
+//!
+//! let pkce = Pkce::new();
+//! let url = oauth.authorization_url("random-state", Some(&pkce));
+//! println!("Send the seller to {}", url);
+//!
+//! // ... later, on the redirect URI
+//! let token = oauth.exchange_code("TG-CODE", Some(pkce.verifier())).await?;
+//! let session = OAuthSession::new(oauth, token);
+//! let seller = MercadoPagoClient::builder("")
+//!     .oauth_session(session)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::client::MercadoPagoClient;
+use crate::credentials::{ExposeSecret, SecretString};
+use crate::error::Error;
+use crate::models::oauth::{OAuthToken, OAuthTokenRequest};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Mutex;
+
+/// PKCE (RFC 7636) verifier and its S256 challenge.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pkce {
+    verifier: String,
+    challenge: String,
+}
+
+impl Pkce {
+    /// Generates a random code verifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system random number generator is unavailable.
+    pub fn new() -> Self {
+        let mut bytes = [0u8; 32];
+        getrandom::getrandom(&mut bytes).expect("OS random number generator unavailable");
+        Self::from_verifier(&URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    /// Builds the challenge for an existing verifier (e.g., one stored in the
+    /// seller's session between the redirect and the callback).
+    pub fn from_verifier(verifier: &str) -> Self {
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier: verifier.to_string(),
+            challenge,
+        }
+    }
+
+    /// Returns the code verifier, to be sent to [`OAuthClient::exchange_code`].
+    pub fn verifier(&self) -> &str {
+        &self.verifier
+    }
+
+    /// Returns the S256 code challenge, sent in the authorization URL.
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Pkce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkce")
+            .field("challenge", &self.challenge)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Client for the OAuth endpoints of a Mercado Pago application.
+#[derive(Clone)]
+pub struct OAuthClient {
+    client: MercadoPagoClient,
+    client_id: String,
+    client_secret: SecretString,
+    redirect_uri: String,
+    auth_base_url: String,
+}
+
+impl OAuthClient {
+    /// Creates an OAuth client for the application `client_id`.
+    ///
+    /// `client` is used to call `/oauth/token`, so its base URL, timeouts and
+    /// retries apply.
+    pub fn new(
+        client: MercadoPagoClient,
+        client_id: &str,
+        client_secret: &str,
+        redirect_uri: &str,
+    ) -> Self {
+        Self {
+            client,
+            client_id: client_id.to_string(),
+            client_secret: SecretString::from(client_secret),
+            redirect_uri: redirect_uri.to_string(),
+            auth_base_url: "https://auth.mercadopago.com".to_string(),
+        }
+    }
+
+    /// Sets the base URL of the authorization page. Defaults to
+    /// `https://auth.mercadopago.com` (use the country domain, e.g.
+    /// `https://auth.mercadopago.com.br`, if needed).
+    pub fn auth_base_url(mut self, url: &str) -> Self {
+        self.auth_base_url = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Returns the URL the seller must visit to grant access.
+    ///
+    /// `state` is echoed back on the redirect URI and should be a random value
+    /// tied to the seller's session.
+    pub fn authorization_url(&self, state: &str, pkce: Option<&Pkce>) -> String {
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("response_type", "code"),
+            ("platform_id", "mp"),
+            ("state", state),
+            ("redirect_uri", self.redirect_uri.as_str()),
+        ];
+        if let Some(pkce) = pkce {
+            params.push(("code_challenge", pkce.challenge()));
+            params.push(("code_challenge_method", "S256"));
+        }
+
+        let base = format!("{}/authorization", self.auth_base_url);
+        Url::parse_with_params(&base, &params)
+            .map(String::from)
+            .unwrap_or(base)
+    }
+
+    /// Exchanges an authorization code for a seller token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code is invalid or expired, or if the request fails.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        code_verifier: Option<&str>,
+    ) -> Result<OAuthToken, Error> {
+        self.request_token(OAuthTokenRequest {
+            grant_type: "authorization_code".to_string(),
+            code: Some(code.to_string()),
+            redirect_uri: Some(self.redirect_uri.clone()),
+            code_verifier: code_verifier.map(str::to_string),
+            ..self.token_request()
+        })
+        .await
+    }
+
+    /// Obtains a new token from a refresh token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the refresh token is invalid or was already used, or if the request fails.
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<OAuthToken, Error> {
+        self.request_token(OAuthTokenRequest {
+            grant_type: "refresh_token".to_string(),
+            refresh_token: Some(refresh_token.to_string()),
+            ..self.token_request()
+        })
+        .await
+    }
+
+    fn token_request(&self) -> OAuthTokenRequest {
+        OAuthTokenRequest {
+            client_id: self.client_id.clone(),
+            client_secret: self.client_secret.expose_secret().to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn request_token(&self, request: OAuthTokenRequest) -> Result<OAuthToken, Error> {
+        Ok(self
+            .client
+            .post("/oauth/token")
+            .json(&request)
+            .send_json()
+            .await?
+            .data)
+    }
+}
+
+impl fmt::Debug for OAuthClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthClient")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+type RefreshCallback = Arc<dyn Fn(&OAuthToken) + Send + Sync>;
+
+struct SessionState {
+    token: OAuthToken,
+    expires_at: SystemTime,
+}
+
+/// A seller token that refreshes itself before it expires.
+///
+/// Clones share the same token, so a refresh performed through one clone is
+/// seen by all of them. Refresh tokens are single use: register
+/// [`on_refresh`](OAuthSession::on_refresh) to persist every new token.
+#[derive(Clone)]
+pub struct OAuthSession {
+    oauth: OAuthClient,
+    state: Arc<Mutex<SessionState>>,
+    refresh_margin: Duration,
+    on_refresh: Option<RefreshCallback>,
+}
+
+impl OAuthSession {
+    /// Creates a session for a token that was just issued.
+    pub fn new(oauth: OAuthClient, token: OAuthToken) -> Self {
+        let expires_at = SystemTime::now() + Duration::from_secs(token.expires_in);
+        Self {
+            oauth,
+            state: Arc::new(Mutex::new(SessionState { token, expires_at })),
+            refresh_margin: Duration::from_secs(300),
+            on_refresh: None,
+        }
+    }
+
+    /// Sets when the token expires, for tokens loaded from storage.
+    ///
+    /// Waits for a refresh in progress on a clone of the session to finish.
+    pub async fn expires_at(self, expires_at: SystemTime) -> Self {
+        self.state.lock().await.expires_at = expires_at;
+        self
+    }
+
+    /// Refreshes the token this long before it expires. Defaults to 5 minutes.
+    pub fn refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Registers a callback invoked with every refreshed token.
+    pub fn on_refresh<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&OAuthToken) + Send + Sync + 'static,
+    {
+        self.on_refresh = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns a valid access token, refreshing it first if it is about to expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a refresh is needed and fails.
+    pub async fn access_token(&self) -> Result<SecretString, Error> {
+        let mut state = self.state.lock().await;
+        if SystemTime::now() + self.refresh_margin >= state.expires_at {
+            self.refresh_locked(&mut state).await?;
+        }
+        Ok(state.token.access_token.clone())
+    }
+
+    /// Refreshes the token now, regardless of its expiration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token has no refresh token or the refresh fails.
+    pub async fn refresh(&self) -> Result<OAuthToken, Error> {
+        let mut state = self.state.lock().await;
+        self.refresh_locked(&mut state).await?;
+        Ok(state.token.clone())
+    }
+
+    /// Refreshes the token unless a clone already replaced `rejected`.
+    ///
+    /// Requests that got a `401` with the same token at the same time trigger
+    /// a single grant; the rest reuse its result.
+    pub(crate) async fn refresh_rejected(&self, rejected: &SecretString) -> Result<(), Error> {
+        let mut state = self.state.lock().await;
+        if state.token.access_token.expose_secret() != rejected.expose_secret() {
+            return Ok(());
+        }
+        self.refresh_locked(&mut state).await
+    }
+
+    /// Returns the current token without refreshing it.
+    pub async fn token(&self) -> OAuthToken {
+        self.state.lock().await.token.clone()
+    }
+
+    async fn refresh_locked(&self, state: &mut SessionState) -> Result<(), Error> {
+        let refresh_token = state
+            .token
+            .refresh_token
+            .as_ref()
+            .map(ExposeSecret::expose_secret)
+            .ok_or_else(|| Error::Internal("OAuth token has no refresh token".to_string()))?;
+
+        #[cfg(feature = "logging")]
+        tracing::info!("Refreshing OAuth token for user {}", state.token.user_id);
+
+        let token = self.oauth.refresh_token(refresh_token).await?;
+        state.expires_at = SystemTime::now() + Duration::from_secs(token.expires_in);
+        state.token = token;
+
+        if let Some(callback) = &self.on_refresh {
+            callback(&state.token);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for OAuthSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthSession")
+            .field("oauth", &self.oauth)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,311 @@
+//! Rendering of QR payloads to SVG, PNG and terminal output.
+//!
+//! Available with the `qr-render` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use mercadopago_sdk::models::instore::QrOrderResponse;
+//! use mercadopago_sdk::qr::ErrorCorrection;
+//!
+//! let response = QrOrderResponse {
+//!     qr_data: "00020101021243650016COM.MERCADOLIBRE".to_string(),
+//!     in_store_order_id: "order-1".to_string(),
+//! };
+//!
+//! let svg = response.to_svg().unwrap();
+//! assert!(svg.starts_with("<svg"));
+//!
+//! let png = response
+//!     .qr_renderer()
+//!     .quiet_zone(2)
+//!     .error_correction(ErrorCorrection::Quartile)
+//!     .to_png(300)
+//!     .unwrap();
+//! assert!(!png.is_empty());
+//! ```
+use crate::error::Error;
+use crate::models::instore::QrOrderResponse;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use image::imageops::{self, FilterType};
+use image::{ImageFormat, Luma, Rgba, RgbaImage};
+use qrcode::{Color, EcLevel, QrCode};
+use std::fmt::Write;
+use std::io::Cursor;
+
+/// Error-correction level of the rendered QR code.
+///
+/// Higher levels tolerate more damage (or a larger center logo) at the cost
+/// of a denser code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorCorrection {
+    /// Recovers about 7% of the code.
+    Low,
+    /// Recovers about 15% of the code.
+    #[default]
+    Medium,
+    /// Recovers about 25% of the code.
+    Quartile,
+    /// Recovers about 30% of the code.
+    High,
+}
+
+impl From<ErrorCorrection> for EcLevel {
+    fn from(level: ErrorCorrection) -> Self {
+        match level {
+            ErrorCorrection::Low => EcLevel::L,
+            ErrorCorrection::Medium => EcLevel::M,
+            ErrorCorrection::Quartile => EcLevel::Q,
+            ErrorCorrection::High => EcLevel::H,
+        }
+    }
+}
+
+/// Image placed over the center of the code.
+#[derive(Debug, Clone)]
+struct Logo {
+    png: Vec<u8>,
+    ratio: f32,
+}
+
+/// Renders a QR payload with configurable quiet zone, error correction and logo.
+///
+/// Use [`QrOrderResponse::qr_renderer`] or [`QrRenderer::new`] to create one.
+#[derive(Debug, Clone)]
+pub struct QrRenderer<'a> {
+    data: &'a str,
+    quiet_zone: u32,
+    error_correction: ErrorCorrection,
+    logo: Option<Logo>,
+}
+
+impl<'a> QrRenderer<'a> {
+    /// Creates a renderer for an arbitrary QR payload.
+    pub fn new(data: &'a str) -> Self {
+        Self {
+            data,
+            quiet_zone: 4,
+            error_correction: ErrorCorrection::default(),
+            logo: None,
+        }
+    }
+
+    /// Sets the width of the blank border, in modules. Defaults to 4.
+    pub fn quiet_zone(mut self, modules: u32) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    /// Sets the error-correction level. Defaults to [`ErrorCorrection::Medium`].
+    pub fn error_correction(mut self, level: ErrorCorrection) -> Self {
+        self.error_correction = level;
+        self
+    }
+
+    /// Places a PNG logo over the center of the code, covering `ratio` of its
+    /// width (clamped to 0.3).
+    ///
+    /// The logo hides part of the code, so the error-correction level is raised
+    /// to [`ErrorCorrection::High`] when a lower one is configured. Logos are
+    /// only drawn in SVG and PNG output.
+    pub fn logo(mut self, png: Vec<u8>, ratio: f32) -> Self {
+        self.logo = Some(Logo {
+            png,
+            ratio: ratio.clamp(0.0, 0.3),
+        });
+        self
+    }
+
+    /// Renders the code as an SVG document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code or the logo is not a valid PNG.
+    pub fn to_svg(&self) -> Result<String, Error> {
+        let matrix = self.matrix()?;
+        let size = matrix.size_with_quiet_zone(self.quiet_zone);
+
+        let mut svg = format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" viewBox="0 0 {size} {size}" shape-rendering="crispEdges"><rect width="{size}" height="{size}" fill="#ffffff"/><path fill="#000000" d=""##
+        );
+        for (x, y) in matrix.dark_modules() {
+            let _ = write!(
+                svg,
+                "M{} {}h1v1h-1z",
+                x + self.quiet_zone as usize,
+                y + self.quiet_zone as usize
+            );
+        }
+        svg.push_str(r#""/>"#);
+
+        if let Some(logo) = &self.logo {
+            image::load_from_memory_with_format(&logo.png, ImageFormat::Png)
+                .map_err(|e| Error::Internal(format!("Invalid QR logo: {}", e)))?;
+            let side = size as f32 * logo.ratio;
+            let offset = (size as f32 - side) / 2.0;
+            let _ = write!(
+                svg,
+                r##"<rect x="{offset}" y="{offset}" width="{side}" height="{side}" fill="#ffffff"/><image x="{offset}" y="{offset}" width="{side}" height="{side}" href="data:image/png;base64,{}"/>"##,
+                STANDARD.encode(&logo.png)
+            );
+        }
+
+        svg.push_str("</svg>");
+        Ok(svg)
+    }
+
+    /// Renders the code as a square PNG image of `size` pixels per side.
+    ///
+    /// Modules are scaled to whole pixels and the code is centered, so the
+    /// border may be slightly wider than the configured quiet zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code, `size` is too
+    /// small to draw one pixel per module, or the logo is not a valid PNG.
+    pub fn to_png(&self, size: u32) -> Result<Vec<u8>, Error> {
+        let matrix = self.matrix()?;
+        let modules = matrix.size_with_quiet_zone(self.quiet_zone) as u32;
+        let scale = size / modules;
+        if scale == 0 {
+            return Err(Error::Internal(format!(
+                "QR image size {}px is smaller than the code ({} modules)",
+                size, modules
+            )));
+        }
+
+        let offset = (size - modules * scale) / 2 + self.quiet_zone * scale;
+        let mut img = image::ImageBuffer::from_pixel(size, size, Luma([255u8]));
+        for (x, y) in matrix.dark_modules() {
+            let left = offset + x as u32 * scale;
+            let top = offset + y as u32 * scale;
+            for py in top..top + scale {
+                for px in left..left + scale {
+                    img.put_pixel(px, py, Luma([0u8]));
+                }
+            }
+        }
+
+        let mut canvas: RgbaImage = image::DynamicImage::ImageLuma8(img).to_rgba8();
+        if let Some(logo) = &self.logo {
+            let decoded = image::load_from_memory_with_format(&logo.png, ImageFormat::Png)
+                .map_err(|e| Error::Internal(format!("Invalid QR logo: {}", e)))?;
+            let side = ((size as f32) * logo.ratio) as u32;
+            if side > 0 {
+                let resized = decoded.resize(side, side, FilterType::Triangle);
+                let x = (size - resized.width()) / 2;
+                let y = (size - resized.height()) / 2;
+                let backing =
+                    RgbaImage::from_pixel(resized.width(), resized.height(), Rgba([255; 4]));
+                imageops::overlay(&mut canvas, &backing, x as i64, y as i64);
+                imageops::overlay(&mut canvas, &resized, x as i64, y as i64);
+            }
+        }
+
+        let mut bytes = Vec::new();
+        canvas
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .map_err(|e| Error::Internal(format!("Failed to encode QR image: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// Renders the code with Unicode half blocks, two modules per character row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code.
+    pub fn to_terminal_string(&self) -> Result<String, Error> {
+        let matrix = self.matrix()?;
+        let qz = self.quiet_zone as usize;
+        let size = matrix.size_with_quiet_zone(self.quiet_zone);
+        let dark = |x: usize, y: usize| x >= qz && y >= qz && matrix.is_dark(x - qz, y - qz);
+
+        let mut out = String::new();
+        for y in (0..size).step_by(2) {
+            for x in 0..size {
+                let top = dark(x, y);
+                let bottom = y + 1 < size && dark(x, y + 1);
+                out.push(match (top, bottom) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                });
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    fn matrix(&self) -> Result<Matrix, Error> {
+        let level = match (&self.logo, self.error_correction) {
+            (Some(_), ErrorCorrection::Low | ErrorCorrection::Medium) => ErrorCorrection::High,
+            (_, level) => level,
+        };
+        let code = QrCode::with_error_correction_level(self.data, level.into())
+            .map_err(|e| Error::Internal(format!("Failed to encode QR code: {}", e)))?;
+        Ok(Matrix {
+            width: code.width(),
+            colors: code.to_colors(),
+        })
+    }
+}
+
+struct Matrix {
+    width: usize,
+    colors: Vec<Color>,
+}
+
+impl Matrix {
+    fn size_with_quiet_zone(&self, quiet_zone: u32) -> usize {
+        self.width + 2 * quiet_zone as usize
+    }
+
+    fn is_dark(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.width && self.colors[y * self.width + x] == Color::Dark
+    }
+
+    fn dark_modules(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.colors
+            .iter()
+            .enumerate()
+            .filter(|(_, color)| **color == Color::Dark)
+            .map(|(i, _)| (i % self.width, i / self.width))
+    }
+}
+
+impl QrOrderResponse {
+    /// Returns a [`QrRenderer`] for [`qr_data`](QrOrderResponse::qr_data) to
+    /// customize the quiet zone, error correction or logo.
+    pub fn qr_renderer(&self) -> QrRenderer<'_> {
+        QrRenderer::new(&self.qr_data)
+    }
+
+    /// Renders `qr_data` as an SVG document with the default options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code.
+    pub fn to_svg(&self) -> Result<String, Error> {
+        self.qr_renderer().to_svg()
+    }
+
+    /// Renders `qr_data` as a PNG image of `size` pixels per side with the default options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code or `size` is too small.
+    pub fn to_png(&self, size: u32) -> Result<Vec<u8>, Error> {
+        self.qr_renderer().to_png(size)
+    }
+
+    /// Renders `qr_data` for printing in a terminal with the default options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload does not fit in a QR code.
+    pub fn to_terminal_string(&self) -> Result<String, Error> {
+        self.qr_renderer().to_terminal_string()
+    }
+}
@@ -0,0 +1,401 @@
+//! Reconciliation of local orders against Mercado Pago payments.
+//!
+//! [`reconcile`] takes the orders recorded by your system and the payments
+//! and refunds reported by a [`PaymentSource`] for a date window, and sorts
+//! every order and payment into a [`ReconciliationReport`]. Orders and
+//! payments are joined by `external_reference`.
+//!
+//! Only *settled* payments (approved, authorized or refunded) count: a
+//! rejected attempt followed by an approved retry is a regular match, not a
+//! duplicate. The report is sorted by external reference and payment ID, so
+//! the same inputs always produce the same report.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::reconcile::{self, DateWindow, LocalOrder};
+//!
+//! # async fn run() -> Result<(), mercadopago_sdk::Error> {
+//! let client = MercadoPagoClient::builder("YOUR_ACCESS_TOKEN").build()?;
+//! let orders = vec![LocalOrder::new("ORDER-1", 100.0, "ARS")];
+//! let window = DateWindow::new("2026-10-01T00:00:00.000-03:00", "2026-10-02T00:00:00.000-03:00");
+//!
+//! let report = reconcile::reconcile(&client, orders, &window).await?;
+//! for mismatch in &report.amount_mismatches {
+//!     println!("{}: expected {}, got {}", mismatch.order.external_reference,
+//!         mismatch.order.amount, mismatch.payment.transaction_amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::client::MercadoPagoClient;
+use crate::error::Error;
+use crate::models::payments::{PaymentResponse, PaymentStatus};
+use crate::models::refunds::RefundResponse;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+#[cfg(feature = "testing")]
+use std::collections::HashMap;
+use std::collections::btree_map::Entry;
+
+const PAGE_SIZE: u32 = 100;
+
+/// An order as recorded by your system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalOrder {
+    /// External reference sent to Mercado Pago with the payment.
+    pub external_reference: String,
+    /// Amount the order was expected to be paid for.
+    pub amount: f64,
+    /// Currency of the amount (e.g., "ARS").
+    pub currency: String,
+}
+
+impl LocalOrder {
+    /// Creates a local order.
+    pub fn new(external_reference: &str, amount: f64, currency: &str) -> Self {
+        Self {
+            external_reference: external_reference.to_string(),
+            amount,
+            currency: currency.to_string(),
+        }
+    }
+}
+
+/// Date window of the payments to reconcile, in ISO 8601 format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateWindow {
+    /// Start of the window.
+    pub begin: String,
+    /// End of the window.
+    pub end: String,
+}
+
+impl DateWindow {
+    /// Creates a window between `begin` and `end`.
+    pub fn new(begin: &str, end: &str) -> Self {
+        Self {
+            begin: begin.to_string(),
+            end: end.to_string(),
+        }
+    }
+}
+
+/// Where payments and refunds are read from.
+///
+/// Implemented by [`MercadoPagoClient`], and by `InMemorySource` for tests
+/// (requires the `testing` feature).
+#[async_trait]
+pub trait PaymentSource: Send + Sync {
+    /// Returns every payment created within `window`.
+    async fn payments(&self, window: &DateWindow) -> Result<Vec<PaymentResponse>, Error>;
+
+    /// Returns the refunds of a payment.
+    async fn refunds(&self, payment_id: u64) -> Result<Vec<RefundResponse>, Error>;
+}
+
+#[async_trait]
+impl PaymentSource for MercadoPagoClient {
+    async fn payments(&self, window: &DateWindow) -> Result<Vec<PaymentResponse>, Error> {
+        let mut payments = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let page = self
+                .search_payments_generic(&[
+                    ("range", "date_created".to_string()),
+                    ("begin_date", window.begin.clone()),
+                    ("end_date", window.end.clone()),
+                    ("sort", "date_created".to_string()),
+                    ("criteria", "asc".to_string()),
+                    ("limit", PAGE_SIZE.to_string()),
+                    ("offset", offset.to_string()),
+                ])
+                .await?;
+
+            let received = page.results.len() as u32;
+            payments.extend(page.results);
+            offset += received;
+            if received == 0 || offset >= page.paging.total {
+                return Ok(payments);
+            }
+        }
+    }
+
+    async fn refunds(&self, payment_id: u64) -> Result<Vec<RefundResponse>, Error> {
+        self.list_refunds(payment_id).await
+    }
+}
+
+/// A [`PaymentSource`] backed by in-memory data, for tests.
+///
+/// Returns all its payments regardless of the window.
+#[cfg(feature = "testing")]
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySource {
+    payments: Vec<PaymentResponse>,
+    refunds: HashMap<u64, Vec<RefundResponse>>,
+}
+
+#[cfg(feature = "testing")]
+impl InMemorySource {
+    /// Creates an empty source.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a payment.
+    pub fn payment(mut self, payment: PaymentResponse) -> Self {
+        self.payments.push(payment);
+        self
+    }
+
+    /// Adds a refund of `refund.payment_id`.
+    pub fn refund(mut self, refund: RefundResponse) -> Self {
+        self.refunds
+            .entry(refund.payment_id)
+            .or_default()
+            .push(refund);
+        self
+    }
+}
+
+#[cfg(feature = "testing")]
+#[async_trait]
+impl PaymentSource for InMemorySource {
+    async fn payments(&self, _window: &DateWindow) -> Result<Vec<PaymentResponse>, Error> {
+        Ok(self.payments.clone())
+    }
+
+    async fn refunds(&self, payment_id: u64) -> Result<Vec<RefundResponse>, Error> {
+        Ok(self.refunds.get(&payment_id).cloned().unwrap_or_default())
+    }
+}
+
+/// An order paid with the expected amount.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matched {
+    /// The local order.
+    pub order: LocalOrder,
+    /// The payment of the order.
+    pub payment: PaymentResponse,
+}
+
+/// An order paid with a different amount or currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmountMismatch {
+    /// The local order.
+    pub order: LocalOrder,
+    /// The payment of the order.
+    pub payment: PaymentResponse,
+}
+
+/// An order with more than one settled payment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplicate {
+    /// The local order.
+    pub order: LocalOrder,
+    /// The settled payments of the order, sorted by ID.
+    pub payments: Vec<PaymentResponse>,
+}
+
+/// An order whose payment was partially or fully refunded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Refunded {
+    /// The local order.
+    pub order: LocalOrder,
+    /// The payment of the order.
+    pub payment: PaymentResponse,
+    /// Sum of the approved refunds.
+    pub refunded_amount: f64,
+}
+
+/// Result of [`reconcile`].
+///
+/// Every local order appears in exactly one of `matched`,
+/// `amount_mismatches`, `missing_remotely`, `duplicates`,
+/// `partially_refunded`, `refunded` and `duplicate_orders`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReconciliationReport {
+    /// Orders paid with the expected amount and currency.
+    pub matched: Vec<Matched>,
+    /// Orders paid with a different amount or currency.
+    pub amount_mismatches: Vec<AmountMismatch>,
+    /// Orders without a settled payment in the window.
+    pub missing_remotely: Vec<LocalOrder>,
+    /// Settled payments that match no local order.
+    pub unknown_remote: Vec<PaymentResponse>,
+    /// Orders with more than one settled payment.
+    pub duplicates: Vec<Duplicate>,
+    /// Orders whose payment was partially refunded.
+    pub partially_refunded: Vec<Refunded>,
+    /// Orders whose payment was fully refunded.
+    pub refunded: Vec<Refunded>,
+    /// Local orders that repeat the external reference of an earlier order.
+    /// Only the first order with a reference is reconciled; these are not.
+    pub duplicate_orders: Vec<LocalOrder>,
+}
+
+impl ReconciliationReport {
+    /// Returns `true` if every order matched and no unknown payment was found.
+    pub fn is_clean(&self) -> bool {
+        self.amount_mismatches.is_empty()
+            && self.missing_remotely.is_empty()
+            && self.unknown_remote.is_empty()
+            && self.duplicates.is_empty()
+            && self.partially_refunded.is_empty()
+            && self.refunded.is_empty()
+            && self.duplicate_orders.is_empty()
+    }
+}
+
+impl MercadoPagoClient {
+    /// Reconciles `orders` against the payments created within `window`.
+    ///
+    /// See [`reconcile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching payments or refunds fails.
+    pub async fn reconcile<I>(
+        &self,
+        orders: I,
+        window: &DateWindow,
+    ) -> Result<ReconciliationReport, Error>
+    where
+        I: IntoIterator<Item = LocalOrder>,
+    {
+        reconcile(self, orders, window).await
+    }
+}
+
+/// Reconciles `orders` against the payments `source` reports for `window`.
+///
+/// If several local orders share an external reference, the first one is
+/// reconciled and the others are reported in
+/// [`duplicate_orders`](ReconciliationReport::duplicate_orders).
+///
+/// # Errors
+///
+/// Returns an error if fetching payments or refunds fails.
+pub async fn reconcile<S, I>(
+    source: &S,
+    orders: I,
+    window: &DateWindow,
+) -> Result<ReconciliationReport, Error>
+where
+    S: PaymentSource + ?Sized,
+    I: IntoIterator<Item = LocalOrder>,
+{
+    let mut report = ReconciliationReport::default();
+    let mut local: BTreeMap<String, LocalOrder> = BTreeMap::new();
+    for order in orders {
+        match local.entry(order.external_reference.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(order);
+            }
+            Entry::Occupied(_) => report.duplicate_orders.push(order),
+        }
+    }
+    report
+        .duplicate_orders
+        .sort_by(|a, b| a.external_reference.cmp(&b.external_reference));
+
+    let mut remote: BTreeMap<String, Vec<PaymentResponse>> = BTreeMap::new();
+
+    let mut payments = source.payments(window).await?;
+    payments.sort_by_key(|p| p.id);
+    payments.dedup_by_key(|p| p.id);
+
+    for payment in payments.into_iter().filter(is_settled) {
+        match payment.external_reference.as_deref() {
+            Some(reference) if local.contains_key(reference) => remote
+                .entry(reference.to_string())
+                .or_default()
+                .push(payment),
+            _ => report.unknown_remote.push(payment),
+        }
+    }
+    report
+        .unknown_remote
+        .sort_by(|a, b| (&a.external_reference, a.id).cmp(&(&b.external_reference, b.id)));
+
+    for (reference, order) in local {
+        let mut settled = remote.remove(&reference).unwrap_or_default();
+        match settled.len() {
+            0 => report.missing_remotely.push(order),
+            1 => {
+                let payment = settled.remove(0);
+                if !amount_matches(&order, &payment) {
+                    report
+                        .amount_mismatches
+                        .push(AmountMismatch { order, payment });
+                } else if may_have_refunds(&payment) {
+                    let refunded_amount = refunded_amount(source, payment.id).await?;
+                    if refunded_amount <= 0.0 {
+                        report.matched.push(Matched { order, payment });
+                    } else if cents(refunded_amount) < cents(payment.transaction_amount) {
+                        report.partially_refunded.push(Refunded {
+                            order,
+                            payment,
+                            refunded_amount,
+                        });
+                    } else {
+                        report.refunded.push(Refunded {
+                            order,
+                            payment,
+                            refunded_amount,
+                        });
+                    }
+                } else {
+                    report.matched.push(Matched { order, payment });
+                }
+            }
+            _ => report.duplicates.push(Duplicate {
+                order,
+                payments: settled,
+            }),
+        }
+    }
+
+    Ok(report)
+}
+
+fn is_settled(payment: &PaymentResponse) -> bool {
+    matches!(
+        payment.payment_status(),
+        PaymentStatus::Approved | PaymentStatus::Authorized | PaymentStatus::Refunded
+    )
+}
+
+fn may_have_refunds(payment: &PaymentResponse) -> bool {
+    payment.payment_status() == PaymentStatus::Refunded
+        || payment.status_detail.as_deref() == Some("partially_refunded")
+}
+
+fn amount_matches(order: &LocalOrder, payment: &PaymentResponse) -> bool {
+    let same_currency = payment
+        .currency_id
+        .as_deref()
+        .is_none_or(|currency| currency.eq_ignore_ascii_case(&order.currency));
+    same_currency && cents(order.amount) == cents(payment.transaction_amount)
+}
+
+async fn refunded_amount<S: PaymentSource + ?Sized>(
+    source: &S,
+    payment_id: u64,
+) -> Result<f64, Error> {
+    let refunds = source.refunds(payment_id).await?;
+    let total: i64 = refunds
+        .iter()
+        .filter(|refund| refund.status == "approved")
+        .map(|refund| cents(refund.amount))
+        .sum();
+    Ok(total as f64 / 100.0)
+}
+
+/// Amounts are compared in cents to avoid floating point noise.
+fn cents(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
@@ -0,0 +1,309 @@
+//! Masking of personal data and secrets in `Debug` output and error messages.
+//!
+//! Models that carry payer data (`Payer`, `PayerResponse`, `PreferencePayer`,
+//! `Identification`, `Phone`, addresses, `User`, ...) implement `Debug` by
+//! hand, so logging them with `{:?}` prints masked values:
+//!
+//! | Data | Example |
+//! |---|---|
+//! | Email | `j***@gmail.com` |
+//! | First and last name | `J***` |
+//! | Identification and phone numbers | `****5678` |
+//! | Street name and number | `***` |
+//!
+//! The same masking applies to the response bodies quoted in
+//! [`Error::Serialization`](crate::Error::Serialization) messages and in the
+//! `Debug` output of [`ApiError`](crate::error::ApiError) and
+//! [`ApiResponse`](crate::client::ApiResponse).
+//!
+//! Personal data masking is on by default and can be turned off for the whole
+//! process with [`set_pii_redaction`], e.g., in local development. Secrets
+//! (access and refresh tokens, client secrets, passwords) are always printed
+//! as `[REDACTED]`.
+//!
+//! ```
+//! use mercadopago_sdk::models::payments::Identification;
+//!
+//! let id = Identification {
+//!     id_type: "DNI".to_string(),
+//!     number: "12345678".to_string(),
+//! };
+//! assert_eq!(
+//!     format!("{:?}", id),
+//!     r#"Identification { id_type: "DNI", number: "****5678" }"#
+//! );
+//! ```
+use crate::credentials::SecretString;
+use serde_json::Value;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PII_REDACTION: AtomicBool = AtomicBool::new(true);
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Enables or disables personal data masking for the whole process.
+/// Enabled by default. Secrets are redacted regardless.
+pub fn set_pii_redaction(enabled: bool) {
+    PII_REDACTION.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns whether personal data is masked.
+pub fn pii_redaction_enabled() -> bool {
+    PII_REDACTION.load(Ordering::Relaxed)
+}
+
+/// Masks an email address, keeping its first character and domain.
+///
+/// ```
+/// use mercadopago_sdk::redact::mask_email;
+///
+/// assert_eq!(mask_email("john.doe@gmail.com"), "j***@gmail.com");
+/// assert_eq!(mask_email("not-an-email"), "***");
+/// ```
+pub fn mask_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => match local.chars().next() {
+            Some(first) => format!("{}***@{}", first, domain),
+            None => format!("***@{}", domain),
+        },
+        None => "***".to_string(),
+    }
+}
+
+/// Masks a document or phone number, keeping its last four characters.
+///
+/// ```
+/// use mercadopago_sdk::redact::mask_number;
+///
+/// assert_eq!(mask_number("12345678"), "****5678");
+/// assert_eq!(mask_number("123"), "****");
+/// ```
+pub fn mask_number(number: &str) -> String {
+    const VISIBLE: usize = 4;
+
+    let len = number.chars().count();
+    if len <= VISIBLE {
+        return "****".to_string();
+    }
+    let tail: String = number.chars().skip(len - VISIBLE).collect();
+    format!("****{}", tail)
+}
+
+/// Masks a name, keeping its first character.
+///
+/// ```
+/// use mercadopago_sdk::redact::mask_name;
+///
+/// assert_eq!(mask_name("Juan"), "J***");
+/// ```
+pub fn mask_name(name: &str) -> String {
+    match name.chars().next() {
+        Some(first) => format!("{}***", first),
+        None => String::new(),
+    }
+}
+
+/// How a field is masked.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Mask {
+    Email,
+    Name,
+    Number,
+    Street,
+    Secret,
+}
+
+impl Mask {
+    /// Masks `value`, or returns `None` if it should be printed as is.
+    fn apply(self, value: &str) -> Option<String> {
+        match self {
+            Mask::Secret => Some(self.mask(value)),
+            _ if !pii_redaction_enabled() => None,
+            _ => Some(self.mask(value)),
+        }
+    }
+
+    /// Masks `value` regardless of [`set_pii_redaction`].
+    fn mask(self, value: &str) -> String {
+        match self {
+            Mask::Secret => REDACTED.to_string(),
+            Mask::Email => mask_email(value),
+            Mask::Name => mask_name(value),
+            Mask::Number => mask_number(value),
+            Mask::Street => "***".to_string(),
+        }
+    }
+}
+
+/// Field values that can be printed masked by [`masked`].
+pub(crate) trait MaskedDebug {
+    fn fmt_masked(&self, mask: Mask, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl MaskedDebug for String {
+    fn fmt_masked(&self, mask: Mask, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match mask.apply(self) {
+            Some(masked) => fmt::Debug::fmt(&masked, f),
+            None => fmt::Debug::fmt(self, f),
+        }
+    }
+}
+
+impl MaskedDebug for SecretString {
+    fn fmt_masked(&self, _mask: Mask, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(REDACTED, f)
+    }
+}
+
+impl<T: MaskedDebug> MaskedDebug for Option<T> {
+    fn fmt_masked(&self, mask: Mask, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => f.debug_tuple("Some").field(&masked(value, mask)).finish(),
+            None => f.write_str("None"),
+        }
+    }
+}
+
+/// Wraps a field for `Debug` output, masked as `mask`.
+pub(crate) fn masked<T: MaskedDebug + ?Sized>(value: &T, mask: Mask) -> impl fmt::Debug + '_ {
+    struct Masked<'a, T: ?Sized>(&'a T, Mask);
+
+    impl<T: MaskedDebug + ?Sized> fmt::Debug for Masked<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_masked(self.1, f)
+        }
+    }
+
+    Masked(value, mask)
+}
+
+/// Masks personal data and secrets in an API payload, by field name.
+pub(crate) fn redact_json(value: &mut Value) {
+    redact_in(value, None, false);
+}
+
+/// Like [`redact_json`], but masks personal data even if
+/// [`set_pii_redaction`] turned masking off, e.g., before saving a payload.
+#[cfg(feature = "testing")]
+pub(crate) fn scrub_json(value: &mut Value) {
+    redact_in(value, None, true);
+}
+
+/// Masks personal data and secrets in a JSON body. Bodies that are not valid
+/// JSON (e.g., truncated ones) are masked with [`redact_text`].
+pub(crate) fn redact_body(body: &str) -> String {
+    match serde_json::from_str::<Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => redact_text(body),
+    }
+}
+
+/// Masks the string values of known fields in text that looks like JSON but
+/// does not parse. Without the nesting to tell a payer's `name` from an
+/// item's, every `name` and `number` is masked. A value cut off by the end of
+/// the text is masked up to the end.
+fn redact_text(body: &str) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body;
+
+    while let Some(open) = rest.find('"') {
+        let (before, after_open) = rest.split_at(open + 1);
+        out.push_str(before);
+        let Some(close) = string_end(after_open) else {
+            rest = after_open;
+            break;
+        };
+        let key = &after_open[..close];
+        out.push_str(&after_open[..=close]);
+        rest = &after_open[close + 1..];
+
+        let Some(mask) = field_mask(key, None).or(match key {
+            "name" => Some(Mask::Name),
+            "number" => Some(Mask::Number),
+            _ => None,
+        }) else {
+            continue;
+        };
+        let Some(value) = rest
+            .trim_start()
+            .strip_prefix(':')
+            .and_then(|r| r.trim_start().strip_prefix('"'))
+        else {
+            continue;
+        };
+
+        out.push_str(&rest[..rest.len() - value.len()]);
+        let end = string_end(value).unwrap_or(value.len());
+        let raw = &value[..end];
+        match mask.apply(raw) {
+            Some(masked) => out.push_str(&masked),
+            None => out.push_str(raw),
+        }
+        rest = &value[end..];
+        if let Some(after_close) = rest.strip_prefix('"') {
+            out.push('"');
+            rest = after_close;
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Returns the index of the quote closing a JSON string whose opening quote
+/// precedes `s`.
+fn string_end(s: &str) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn redact_in(value: &mut Value, parent: Option<&str>, always: bool) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    Value::String(s) => {
+                        let masked = field_mask(key, parent)
+                            .and_then(|m| if always { Some(m.mask(s)) } else { m.apply(s) });
+                        if let Some(masked) = masked {
+                            *s = masked;
+                        }
+                    }
+                    _ => redact_in(value, Some(key), always),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact_in(item, parent, always);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn field_mask(key: &str, parent: Option<&str>) -> Option<Mask> {
+    match (key, parent) {
+        ("access_token" | "refresh_token" | "client_secret" | "password" | "code_verifier", _) => {
+            Some(Mask::Secret)
+        }
+        ("email", _) => Some(Mask::Email),
+        ("first_name" | "last_name" | "surname", _) => Some(Mask::Name),
+        ("name", Some("payer")) => Some(Mask::Name),
+        ("number", Some("identification" | "phone")) => Some(Mask::Number),
+        ("street_name" | "street_number", _) => Some(Mask::Street),
+        _ => None,
+    }
+}
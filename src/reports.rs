@@ -0,0 +1,301 @@
+//! Settlement and release reports.
+//!
+//! The account money reports list every movement of the account: the
+//! settlement report (`/v1/account/settlement_report`) has one row per
+//! transaction, with its fees and settlement date; the release report
+//! (`/v1/account/release_report`) lists the money released to the available
+//! balance. Reports can be generated on a schedule (see [`ReportConfig`]) or on
+//! demand for a date range, and are downloaded as CSV files that
+//! [`parse_csv`] turns into typed rows.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::reports::{self, ReportKind, SettlementRow};
+//!
+//! # async fn run() -> Result<(), mercadopago_sdk::Error> {
+//! let client = MercadoPagoClient::builder("YOUR_ACCESS_TOKEN").build()?;
+//! let settlement = client.reports(ReportKind::Settlement);
+//!
+//! settlement
+//!     .create("2026-10-01T00:00:00Z", "2026-10-02T00:00:00Z")
+//!     .await?;
+//!
+//! // Reports are generated asynchronously; list them to find the file.
+//! if let Some(file) = settlement.list().await?.first() {
+//!     let csv = settlement.download(&file.file_name).await?;
+//!     for row in reports::parse_csv::<SettlementRow>(&csv) {
+//!         let row = row?;
+//!         println!("{:?} {:?} {:?}", row.source_id, row.transaction_type, row.settlement_net_amount);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::client::MercadoPagoClient;
+use crate::error::Error;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::io::Cursor;
+
+pub use crate::models::reports::{
+    ReportColumn, ReportConfig, ReportFile, ReportFrequency, ReportRequest,
+};
+
+/// Type of account money report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportKind {
+    /// Settlement report: one row per transaction, with fees and settlement date.
+    Settlement,
+    /// Release report: money released to the available balance.
+    Release,
+}
+
+impl ReportKind {
+    fn path(self) -> &'static str {
+        match self {
+            ReportKind::Settlement => "/v1/account/settlement_report",
+            ReportKind::Release => "/v1/account/release_report",
+        }
+    }
+}
+
+/// Operations on one type of report, returned by [`MercadoPagoClient::reports`].
+#[derive(Clone)]
+pub struct Reports {
+    client: MercadoPagoClient,
+    kind: ReportKind,
+}
+
+impl MercadoPagoClient {
+    /// Returns the operations on reports of type `kind`.
+    pub fn reports(&self, kind: ReportKind) -> Reports {
+        Reports {
+            client: self.clone(),
+            kind,
+        }
+    }
+}
+
+impl Reports {
+    /// Returns the report configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the report was never configured or if the request fails.
+    pub async fn config(&self) -> Result<ReportConfig, Error> {
+        Ok(self
+            .client
+            .get(&format!("{}/config", self.kind.path()))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Replaces the report configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn update_config(&self, config: &ReportConfig) -> Result<ReportConfig, Error> {
+        Ok(self
+            .client
+            .put(&format!("{}/config", self.kind.path()))
+            .json(config)
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Requests a report for the range between `begin_date` and `end_date`
+    /// (ISO 8601).
+    ///
+    /// The report is generated asynchronously; it shows up in
+    /// [`list`](Reports::list) once ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn create(&self, begin_date: &str, end_date: &str) -> Result<(), Error> {
+        self.client
+            .post(self.kind.path())
+            .json(&ReportRequest {
+                begin_date: begin_date.to_string(),
+                end_date: end_date.to_string(),
+            })
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    /// Lists the generated report files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or if the API returns an error response.
+    pub async fn list(&self) -> Result<Vec<ReportFile>, Error> {
+        Ok(self
+            .client
+            .get(&format!("{}/list", self.kind.path()))
+            .send_json()
+            .await?
+            .data)
+    }
+
+    /// Downloads a report file as CSV text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file does not exist or if the request fails.
+    pub async fn download(&self, file_name: &str) -> Result<String, Error> {
+        Ok(self
+            .client
+            .get(&format!("{}/{}", self.kind.path(), file_name))
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+}
+
+/// A row of the settlement report.
+///
+/// Every field is optional because the columns are configurable (see
+/// [`ReportConfig::columns`]). Headers must be in English
+/// (`report_translation = "en"`, the default).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", default)]
+pub struct SettlementRow {
+    /// ID of the payment, refund or chargeback that originated the row.
+    pub source_id: Option<String>,
+    /// External reference of the payment.
+    pub external_reference: Option<String>,
+    /// Type of movement (e.g., "SETTLEMENT", "REFUND", "CHARGEBACK").
+    pub transaction_type: Option<String>,
+    /// Gross amount of the transaction.
+    pub transaction_amount: Option<f64>,
+    /// Currency of the transaction.
+    pub transaction_currency: Option<String>,
+    /// Date of the transaction.
+    pub transaction_date: Option<String>,
+    /// Mercado Pago fee.
+    pub fee_amount: Option<f64>,
+    /// Marketplace fee.
+    pub mkp_fee_amount: Option<f64>,
+    /// Installment financing fee.
+    pub financing_fee_amount: Option<f64>,
+    /// Shipping cost.
+    pub shipping_fee_amount: Option<f64>,
+    /// Taxes withheld.
+    pub taxes_amount: Option<f64>,
+    /// Coupon discount.
+    pub coupon_amount: Option<f64>,
+    /// Net amount settled.
+    pub settlement_net_amount: Option<f64>,
+    /// Currency of the settled amount.
+    pub settlement_currency: Option<String>,
+    /// Date the money is settled.
+    pub settlement_date: Option<String>,
+    /// Amount actually credited or debited.
+    pub real_amount: Option<f64>,
+    /// Payment method type (e.g., "credit_card").
+    pub payment_method_type: Option<String>,
+    /// Payment method (e.g., "visa").
+    pub payment_method: Option<String>,
+    /// Number of installments.
+    pub installments: Option<u32>,
+    /// ID of the store.
+    pub store_id: Option<String>,
+    /// ID of the point of sale.
+    pub pos_id: Option<String>,
+    /// ID of the order.
+    pub order_id: Option<String>,
+}
+
+/// A row of the release report.
+///
+/// Every field is optional because the columns are configurable.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE", default)]
+pub struct ReleaseRow {
+    /// Date of the release.
+    pub date: Option<String>,
+    /// ID of the payment, refund or withdrawal that originated the row.
+    pub source_id: Option<String>,
+    /// External reference of the payment.
+    pub external_reference: Option<String>,
+    /// Type of record (e.g., "release", "initial_available_balance").
+    pub record_type: Option<String>,
+    /// Description of the movement (e.g., "payment", "refund", "withdrawal").
+    pub description: Option<String>,
+    /// Amount credited.
+    pub net_credit_amount: Option<f64>,
+    /// Amount debited.
+    pub net_debit_amount: Option<f64>,
+    /// Gross amount of the movement.
+    pub gross_amount: Option<f64>,
+    /// Mercado Pago fee.
+    pub mp_fee_amount: Option<f64>,
+    /// Installment financing fee.
+    pub financing_fee_amount: Option<f64>,
+    /// Shipping cost.
+    pub shipping_fee_amount: Option<f64>,
+    /// Taxes withheld.
+    pub taxes_amount: Option<f64>,
+    /// Coupon discount.
+    pub coupon_amount: Option<f64>,
+    /// Balance after the movement.
+    pub balance_amount: Option<f64>,
+    /// Payment method (e.g., "visa").
+    pub payment_method: Option<String>,
+}
+
+/// Iterator over the rows of a report, returned by [`parse_csv`].
+pub struct ReportRows<T> {
+    records: csv::DeserializeRecordsIntoIter<Cursor<Vec<u8>>, T>,
+}
+
+impl<T: DeserializeOwned> Iterator for ReportRows<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.records
+            .next()
+            .map(|row| row.map_err(|e| Error::Serialization(Box::new(e))))
+    }
+}
+
+/// Parses a downloaded report into typed rows (e.g., [`SettlementRow`] or
+/// [`ReleaseRow`]).
+///
+/// The separator (`,` or `;`) is detected from the header line. Columns that
+/// `T` does not know are ignored.
+///
+/// ```
+/// use mercadopago_sdk::reports::{parse_csv, SettlementRow};
+///
+/// let csv = "SOURCE_ID;TRANSACTION_TYPE;TRANSACTION_AMOUNT;FEE_AMOUNT\n\
+///            123;SETTLEMENT;100.00;-4.99\n";
+/// let rows: Vec<SettlementRow> = parse_csv(csv).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(rows[0].source_id.as_deref(), Some("123"));
+/// assert_eq!(rows[0].fee_amount, Some(-4.99));
+/// ```
+pub fn parse_csv<T: DeserializeOwned>(data: &str) -> ReportRows<T> {
+    let data = data.trim_start_matches('\u{feff}');
+    let header = data.lines().next().unwrap_or_default();
+    let delimiter = if header.matches(';').count() > header.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+
+    let records = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(Cursor::new(data.as_bytes().to_vec()))
+        .into_deserialize();
+
+    ReportRows { records }
+}
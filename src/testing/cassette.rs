@@ -0,0 +1,476 @@
+//! Record/replay of API traffic for deterministic tests.
+//!
+//! A [`Cassette`] set on a client with
+//! [`MercadoPagoClientBuilder::cassette`](crate::client::MercadoPagoClientBuilder::cassette)
+//! sits where the client sends each HTTP request, retries included:
+//!
+//! - In [`Mode::Record`] requests go to the API and every request/response
+//!   pair is saved to a JSON file. Tokens never reach the file: the
+//!   `Authorization` header is not recorded, and secrets and personal data in
+//!   the bodies are masked as described in [`redact`](mod@crate::redact),
+//!   regardless of [`set_pii_redaction`](crate::redact::set_pii_redaction).
+//! - In [`Mode::Replay`] nothing is sent. Each request must match the next
+//!   recorded one on method, path with query string, and body (masked the
+//!   same way); the recorded response is returned. A mismatch fails the
+//!   request with an [`Error::Internal`] that shows a diff of the two
+//!   requests.
+//!
+//! Record once against the sandbox, commit the cassette, and replay it in CI.
+//! [`Cassette::from_env`] records when `MERCADO_PAGO_RECORD` is set and
+//! replays otherwise:
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::testing::cassette::Cassette;
+//!
+//! # async fn run() -> Result<(), mercadopago_sdk::Error> {
+//! // MERCADO_PAGO_RECORD=1 cargo test --features testing  # records
+//! // cargo test --features testing                        # replays
+//! let cassette = Cassette::from_env("tests/cassettes/get_payment.json")?;
+//! let token = std::env::var("MERCADO_PAGO_ACCESS_TOKEN").unwrap_or_default();
+//! let client = MercadoPagoClient::builder(&token)
+//!     .cassette(cassette.clone())
+//!     .build()?;
+//!
+//! let payment = client.get_payment(123456789).await?;
+//! assert_eq!(cassette.remaining(), 0);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Bodies that are not JSON, such as multipart uploads, are compared
+//! verbatim.
+use crate::error::Error;
+use crate::redact;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Environment variable that makes [`Cassette::from_env`] record.
+const RECORD_ENV: &str = "MERCADO_PAGO_RECORD";
+
+/// Response headers that are not saved.
+const SKIPPED_HEADERS: &[&str] = &[
+    "connection",
+    "content-length",
+    "date",
+    "keep-alive",
+    "set-cookie",
+    "transfer-encoding",
+];
+
+/// Unchanged lines shown around each change in a body diff.
+const DIFF_CONTEXT: usize = 2;
+
+/// Whether a [`Cassette`] records or replays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Send requests to the API and save them with their responses.
+    Record,
+    /// Answer requests from the saved responses, without sending them.
+    Replay,
+}
+
+/// A request and the response it received.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request, as sent.
+    pub request: RecordedRequest,
+    /// The response the API returned.
+    pub response: RecordedResponse,
+}
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `"POST"`.
+    pub method: String,
+    /// Path and query string, e.g. `"/v1/payments/search?external_reference=A1"`.
+    pub path: String,
+    /// JSON body with secrets and personal data masked. Non-JSON bodies are
+    /// kept as a string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers, by lowercase name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// JSON body with secrets and personal data masked. Non-JSON bodies are
+    /// kept as a string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// The file format.
+#[derive(Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// A recording of API traffic. See the [module docs](self).
+///
+/// Cloning is cheap: clones share the recording, so a test can keep one to
+/// check [`remaining`](Self::remaining) after handing another to the client.
+#[derive(Clone)]
+pub struct Cassette {
+    inner: Arc<CassetteInner>,
+}
+
+struct CassetteInner {
+    path: PathBuf,
+    mode: Mode,
+    state: Mutex<CassetteState>,
+}
+
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    /// Index of the next interaction to replay.
+    next: usize,
+}
+
+impl Cassette {
+    /// Creates an empty cassette that records to `path`. The file is
+    /// (over)written after every request.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(path.as_ref(), Mode::Record, Vec::new())
+    }
+
+    /// Loads the cassette at `path` for replay.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a cassette.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            Error::Internal(format!("Failed to read cassette {}: {}", path.display(), e))
+        })?;
+        let file: CassetteFile = serde_json::from_str(&contents)?;
+        Ok(Self::new(path, Mode::Replay, file.interactions))
+    }
+
+    /// Records to `path` if the `MERCADO_PAGO_RECORD` environment variable is
+    /// set to a non-empty value, and replays from it otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if replaying and the file cannot be loaded.
+    pub fn from_env(path: impl AsRef<Path>) -> Result<Self, Error> {
+        match std::env::var(RECORD_ENV) {
+            Ok(value) if !value.is_empty() => Ok(Self::record(path)),
+            _ => Self::replay(path),
+        }
+    }
+
+    fn new(path: &Path, mode: Mode, interactions: Vec<Interaction>) -> Self {
+        Self {
+            inner: Arc::new(CassetteInner {
+                path: path.to_path_buf(),
+                mode,
+                state: Mutex::new(CassetteState {
+                    interactions,
+                    next: 0,
+                }),
+            }),
+        }
+    }
+
+    /// Returns whether the cassette records or replays.
+    pub fn mode(&self) -> Mode {
+        self.inner.mode
+    }
+
+    /// Returns the path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Returns the recorded interactions.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.state().interactions.clone()
+    }
+
+    /// Returns how many recorded interactions have not been replayed yet.
+    /// Always `0` when recording.
+    pub fn remaining(&self) -> usize {
+        let state = self.state();
+        state.interactions.len() - state.next
+    }
+
+    fn state(&self) -> MutexGuard<'_, CassetteState> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sends `request` and records it, or answers it from the recording.
+    pub(crate) async fn dispatch(
+        &self,
+        client: &Client,
+        request: Request,
+    ) -> Result<Response, Error> {
+        match self.inner.mode {
+            Mode::Record => self.record_request(client, request).await,
+            Mode::Replay => self.replay_request(&request),
+        }
+    }
+
+    async fn record_request(&self, client: &Client, request: Request) -> Result<Response, Error> {
+        let recorded = RecordedRequest::new(&request);
+        let res = client.execute(request).await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.bytes().await?.to_vec();
+
+        let interaction = Interaction {
+            request: recorded,
+            response: RecordedResponse::new(status, &headers, &body),
+        };
+        {
+            let mut state = self.state();
+            state.interactions.push(interaction);
+            state.next = state.interactions.len();
+            self.save(&state.interactions)?;
+        }
+
+        // The caller gets the live response; only the file is masked.
+        Ok(build_response(status, headers, body))
+    }
+
+    fn replay_request(&self, request: &Request) -> Result<Response, Error> {
+        let actual = RecordedRequest::new(request);
+        let mut state = self.state();
+        let index = state.next;
+        let Some(interaction) = state.interactions.get(index) else {
+            return Err(Error::Internal(format!(
+                "Cassette {}: unexpected request #{} {} {}; all {} recorded requests were replayed",
+                self.inner.path.display(),
+                index + 1,
+                actual.method,
+                actual.path,
+                state.interactions.len()
+            )));
+        };
+        if interaction.request != actual {
+            return Err(Error::Internal(mismatch(
+                &self.inner.path,
+                index,
+                &interaction.request,
+                &actual,
+            )));
+        }
+
+        let response = interaction.response.to_response();
+        state.next += 1;
+        Ok(response)
+    }
+
+    fn save(&self, interactions: &[Interaction]) -> Result<(), Error> {
+        let path = &self.inner.path;
+        let file = CassetteFile {
+            interactions: interactions.to_vec(),
+        };
+        let contents = serde_json::to_string_pretty(&file)?;
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| {
+                Error::Internal(format!(
+                    "Failed to create cassette directory {}: {}",
+                    dir.display(),
+                    e
+                ))
+            })?;
+        }
+        std::fs::write(path, contents + "\n").map_err(|e| {
+            Error::Internal(format!(
+                "Failed to write cassette {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+impl std::fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.inner.path)
+            .field("mode", &self.inner.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        let url = request.url();
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        Self {
+            method: request.method().to_string(),
+            path,
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(scrubbed_body),
+        }
+    }
+}
+
+impl RecordedResponse {
+    fn new(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        Self {
+            status: status.as_u16(),
+            headers,
+            body: scrubbed_body(body),
+        }
+    }
+
+    fn to_response(&self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.insert(name, value);
+            }
+        }
+        let body = match &self.body {
+            Some(Value::String(text)) => text.clone().into_bytes(),
+            Some(json) => json.to_string().into_bytes(),
+            None => Vec::new(),
+        };
+        build_response(status, headers, body)
+    }
+}
+
+/// Parses and masks a JSON body; other bodies are kept as a string.
+fn scrubbed_body(body: &[u8]) -> Option<Value> {
+    if body.is_empty() {
+        return None;
+    }
+    match serde_json::from_slice::<Value>(body) {
+        Ok(mut json) => {
+            redact::scrub_json(&mut json);
+            Some(json)
+        }
+        Err(_) => Some(Value::String(String::from_utf8_lossy(body).into_owned())),
+    }
+}
+
+fn build_response(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+/// Describes how `actual` differs from the `recorded` request at `index`.
+fn mismatch(
+    path: &Path,
+    index: usize,
+    recorded: &RecordedRequest,
+    actual: &RecordedRequest,
+) -> String {
+    let mut out = format!(
+        "Cassette {}: request #{} does not match the recording (- recorded, + actual)\n",
+        path.display(),
+        index + 1
+    );
+    if (&recorded.method, &recorded.path) == (&actual.method, &actual.path) {
+        let _ = writeln!(out, "  {} {}", actual.method, actual.path);
+    } else {
+        let _ = writeln!(out, "- {} {}", recorded.method, recorded.path);
+        let _ = writeln!(out, "+ {} {}", actual.method, actual.path);
+    }
+    if recorded.body != actual.body {
+        out.push_str("  body:\n");
+        out.push_str(&diff_lines(
+            &body_text(recorded.body.as_ref()),
+            &body_text(actual.body.as_ref()),
+        ));
+    }
+    out
+}
+
+fn body_text(body: Option<&Value>) -> String {
+    match body {
+        None => "(no body)".to_string(),
+        Some(Value::String(text)) => text.clone(),
+        Some(json) => serde_json::to_string_pretty(json).unwrap_or_default(),
+    }
+}
+
+/// A line diff of `old` and `new`, with [`DIFF_CONTEXT`] unchanged lines
+/// around each change.
+fn diff_lines(old: &str, new: &str) -> String {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence lengths of the suffixes.
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push((' ', a[i]));
+            i += 1;
+            j += 1;
+        } else if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', a[i]));
+            i += 1;
+        } else {
+            lines.push(('+', b[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    let near_change = |k: usize| changed.iter().any(|&c| c.abs_diff(k) <= DIFF_CONTEXT);
+
+    let mut out = String::new();
+    let mut skipped = false;
+    for (k, (tag, line)) in lines.iter().enumerate() {
+        if *tag == ' ' && !near_change(k) {
+            if !skipped {
+                out.push_str("    ...\n");
+                skipped = true;
+            }
+            continue;
+        }
+        skipped = false;
+        let _ = writeln!(out, "{}   {}", tag, line);
+    }
+    out
+}
@@ -0,0 +1,484 @@
+//! A stateful, in-memory Mercado Pago API for integration tests.
+//!
+//! Available with the `testing` feature. [`Simulator`] starts a local HTTP
+//! server that keeps stores, POS, QR orders, preferences, payments and refunds
+//! in memory, validates requests like the real API and answers errors in the
+//! same shape, so [`Error::kind`](crate::Error::kind) and
+//! [`ApiError::cause`](crate::error::ApiError::cause) behave as in production.
+//!
+//! Payments start `pending`. Tests play the buyer and the platform with
+//! control hooks: [`pay_qr_order`](Simulator::pay_qr_order) and
+//! [`pay_preference`](Simulator::pay_preference) create the buyer's payment,
+//! [`approve_payment`](Simulator::approve_payment) and
+//! [`reject_payment`](Simulator::reject_payment) settle it,
+//! [`fire_webhook`](Simulator::fire_webhook) delivers a signed notification
+//! and [`inject_fault`](Simulator::inject_fault) makes the next responses fail
+//! with `429` or `5xx`.
+//!
+//! | Endpoint | Client method |
+//! |---|---|
+//! | `GET /users/me` | [`get_me`](crate::MercadoPagoClient::get_me) |
+//! | `POST /users/{id}/stores`, `GET /users/{id}/stores/search` | [`create_store`](crate::MercadoPagoClient::create_store), [`search_stores`](crate::MercadoPagoClient::search_stores) |
+//! | `POST /pos`, `GET /pos` | [`create_pos`](crate::MercadoPagoClient::create_pos), [`list_pos`](crate::MercadoPagoClient::list_pos) |
+//! | `POST /instore/orders/qr/seller/collectors/{id}/pos/{pos}/qrs` | [`create_qr_order`](crate::MercadoPagoClient::create_qr_order) |
+//! | `POST /checkout/preferences` | [`create_preference`](crate::MercadoPagoClient::create_preference) |
+//! | `POST /v1/payments`, `GET /v1/payments/{id}`, `GET /v1/payments/search` | [`create_payment`](crate::MercadoPagoClient::create_payment), [`get_payment`](crate::MercadoPagoClient::get_payment), [`search_payments`](crate::MercadoPagoClient::search_payments) |
+//! | `POST /v1/payments/{id}/refunds`, `GET /v1/payments/{id}/refunds` | [`create_refund`](crate::MercadoPagoClient::create_refund), [`list_refunds`](crate::MercadoPagoClient::list_refunds) |
+//!
+//! Other endpoints answer `404`.
+//!
+//! To test against real API responses instead, record them once with a
+//! [`cassette::Cassette`] and replay them offline.
+//!
+//! # Example
+//!
+//! ```
+//! use mercadopago_sdk::models::instore::{PosRequest, QrOrderItem, QrOrderRequest, StoreRequest};
+//! use mercadopago_sdk::testing::Simulator;
+//! use mercadopago_sdk::wait::{PaymentOutcome, WaitOptions};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), mercadopago_sdk::Error> {
+//! let sim = Simulator::start().await;
+//! let client = sim.client();
+//!
+//! let user_id = client.user_id().await?;
+//! let store = client
+//!     .create_store(user_id, StoreRequest {
+//!         name: "Sucursal Centro".to_string(),
+//!         external_id: "STORE001".to_string(),
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//! client
+//!     .create_pos(PosRequest {
+//!         name: "Caja 1".to_string(),
+//!         store_id: store.id,
+//!         external_id: "POS001".to_string(),
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//!
+//! let item = QrOrderItem {
+//!     title: "Café".to_string(),
+//!     unit_price: 1500.0,
+//!     quantity: 1,
+//!     unit_measure: "unit".to_string(),
+//!     total_amount: 1500.0,
+//!     ..Default::default()
+//! };
+//! let request = QrOrderRequest {
+//!     external_reference: Some("ORDER-1".to_string()),
+//!     total_amount: 1500.0,
+//!     items: vec![item],
+//!     ..Default::default()
+//! };
+//! client.create_qr_order(user_id, "POS001", request).await?;
+//!
+//! // The buyer scans the code and the payment is approved.
+//! let payment_id = sim.pay_qr_order("POS001");
+//! sim.approve_payment(payment_id);
+//!
+//! let outcome = client.wait_for_payment("ORDER-1", WaitOptions::new()).await?;
+//! assert!(matches!(outcome, PaymentOutcome::Approved(_)));
+//! # Ok(())
+//! # }
+//! ```
+pub mod cassette;
+mod state;
+
+use crate::client::MercadoPagoClient;
+use crate::error::Error;
+use crate::models::payments::PaymentResponse;
+use hmac::{Hmac, Mac};
+use http::{HeaderMap, HeaderValue, StatusCode};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use state::{Reply, State};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use wiremock::matchers::any;
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+const DEFAULT_COLLECTOR_ID: u64 = 123_456_789;
+const DEFAULT_ACCESS_TOKEN: &str = "TEST-simulator-access-token";
+const DEFAULT_WEBHOOK_SECRET: &str = "simulator-webhook-secret";
+
+/// A failure injected with [`Simulator::inject_fault`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// `429 Too Many Requests`, with a `Retry-After` header in whole seconds.
+    RateLimited { retry_after: Duration },
+    /// A `5xx` response with this status code.
+    ServerError(u16),
+    /// The request is handled normally, but the response is delayed, e.g.,
+    /// to trigger the client's timeout.
+    Delay(Duration),
+}
+
+/// State shared between the [`Simulator`] and its HTTP server.
+struct Shared {
+    state: Mutex<State>,
+    faults: Mutex<VecDeque<Fault>>,
+}
+
+impl Shared {
+    /// Locks the state. A hook that panicked (e.g., approving an unknown
+    /// payment) does not make later requests fail.
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn faults(&self) -> MutexGuard<'_, VecDeque<Fault>> {
+        self.faults.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Answers every request to the mock server from the shared state.
+struct Handler(Arc<Shared>);
+
+impl Respond for Handler {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let mut delay = None;
+        let reply = match self.0.faults().pop_front() {
+            Some(Fault::RateLimited { retry_after }) => {
+                return template(Reply::error(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    "too many requests",
+                    &[],
+                ))
+                .insert_header("retry-after", retry_after.as_secs().to_string());
+            }
+            Some(Fault::ServerError(status)) => Reply::error(
+                StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+                "internal server error",
+                &[],
+            ),
+            Some(Fault::Delay(duration)) => {
+                delay = Some(duration);
+                self.handle(request)
+            }
+            None => self.handle(request),
+        };
+
+        match delay {
+            Some(delay) => template(reply).set_delay(delay),
+            None => template(reply),
+        }
+    }
+}
+
+impl Handler {
+    fn handle(&self, request: &Request) -> Reply {
+        let query: Vec<(String, String)> = request.url.query_pairs().into_owned().collect();
+        self.0.state().handle(
+            request.method.as_str(),
+            request.url.path(),
+            &query,
+            &request.headers,
+            &request.body,
+        )
+    }
+}
+
+fn template(reply: Reply) -> ResponseTemplate {
+    ResponseTemplate::new(reply.status.as_u16())
+        .set_body_json(reply.body)
+        .insert_header("x-request-id", state::random_id().as_str())
+}
+
+/// A local, stateful Mercado Pago API. See the [module docs](self).
+///
+/// The server stops when the simulator is dropped.
+pub struct Simulator {
+    server: MockServer,
+    shared: Arc<Shared>,
+    collector_id: u64,
+    access_token: String,
+    webhook_secret: String,
+}
+
+impl Simulator {
+    /// Starts a simulator with the default settings.
+    pub async fn start() -> Self {
+        Self::builder().start().await
+    }
+
+    /// Creates a builder to configure the simulator.
+    pub fn builder() -> SimulatorBuilder {
+        SimulatorBuilder {
+            collector_id: DEFAULT_COLLECTOR_ID,
+            access_token: None,
+            webhook_secret: DEFAULT_WEBHOOK_SECRET.to_string(),
+        }
+    }
+
+    /// Returns the base URL of the simulated API, for
+    /// [`MercadoPagoClientBuilder::base_url`](crate::client::MercadoPagoClientBuilder::base_url).
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns a client for the simulated API.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client fails to initialize.
+    pub fn client(&self) -> MercadoPagoClient {
+        MercadoPagoClient::builder(&self.access_token)
+            .base_url(&self.uri())
+            .build()
+            .expect("failed to build the simulator client")
+    }
+
+    /// Returns the ID of the simulated seller, as returned by `GET /users/me`.
+    pub fn collector_id(&self) -> u64 {
+        self.collector_id
+    }
+
+    /// Returns the secret the simulator signs webhooks with.
+    pub fn webhook_secret(&self) -> &str {
+        &self.webhook_secret
+    }
+
+    /// Returns the current state of a payment, or `None` if it does not exist.
+    pub fn payment(&self, id: u64) -> Option<PaymentResponse> {
+        self.shared.state().get_payment(id)
+    }
+
+    /// The buyer pays the pending QR order of the POS with external ID
+    /// `pos_id`. Returns the ID of the new `pending` payment, which carries
+    /// the order's amount and external reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the POS has no pending QR order.
+    pub fn pay_qr_order(&self, pos_id: &str) -> u64 {
+        self.shared.state().pay_qr_order(pos_id)
+    }
+
+    /// The buyer pays the preference with ID `preference_id`. Returns the ID
+    /// of the new `pending` payment, which carries the items' total and the
+    /// preference's external reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the preference does not exist.
+    pub fn pay_preference(&self, preference_id: &str) -> u64 {
+        self.shared.state().pay_preference(preference_id)
+    }
+
+    /// Approves a pending payment (`approved` / `accredited`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payment does not exist or is not pending.
+    pub fn approve_payment(&self, id: u64) {
+        self.shared
+            .state()
+            .settle_payment(id, "approved", "accredited");
+    }
+
+    /// Rejects a pending payment with `status_detail`, e.g.
+    /// `"cc_rejected_insufficient_amount"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payment does not exist or is not pending.
+    pub fn reject_payment(&self, id: u64, status_detail: &str) {
+        self.shared
+            .state()
+            .settle_payment(id, "rejected", status_detail);
+    }
+
+    /// Makes the next `times` requests fail with `fault`. Faults queue up in
+    /// the order they are injected.
+    pub fn inject_fault(&self, fault: Fault, times: usize) {
+        self.shared
+            .faults()
+            .extend(std::iter::repeat_n(fault, times));
+    }
+
+    /// Builds the signed notification Mercado Pago sends when the payment
+    /// `payment_id` is created or updated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payment does not exist.
+    pub fn webhook(&self, payment_id: u64) -> Webhook {
+        let payment = self
+            .payment(payment_id)
+            .unwrap_or_else(|| panic!("payment {} does not exist", payment_id));
+        let action = if payment.status == "pending" {
+            "payment.created"
+        } else {
+            "payment.updated"
+        };
+        let data_id = payment_id.to_string();
+        let request_id = state::random_id();
+        let ts = state::unix_time();
+        let signature = sign(&self.webhook_secret, &data_id, &request_id, ts);
+
+        Webhook {
+            body: json!({
+                "id": ts,
+                "live_mode": false,
+                "type": "payment",
+                "date_created": state::now(),
+                "user_id": self.collector_id,
+                "api_version": "v1",
+                "action": action,
+                "data": { "id": data_id },
+            }),
+            data_id,
+            request_id,
+            ts,
+            signature,
+        }
+    }
+
+    /// Sends the [`webhook`](Self::webhook) for `payment_id` to `url` and
+    /// returns the status code the receiver answered with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payment does not exist.
+    pub async fn fire_webhook(&self, url: &str, payment_id: u64) -> Result<StatusCode, Error> {
+        let webhook = self.webhook(payment_id);
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let response = reqwest::Client::new()
+            .post(format!("{}{}{}", url, separator, webhook.query()))
+            .headers(webhook.headers())
+            .json(&webhook.body)
+            .send()
+            .await?;
+        Ok(response.status())
+    }
+}
+
+impl std::fmt::Debug for Simulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Simulator")
+            .field("uri", &self.server.uri())
+            .field("collector_id", &self.collector_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A builder for [`Simulator`].
+#[derive(Debug)]
+pub struct SimulatorBuilder {
+    collector_id: u64,
+    access_token: Option<String>,
+    webhook_secret: String,
+}
+
+impl SimulatorBuilder {
+    /// Sets the ID of the simulated seller. Defaults to `123456789`.
+    pub fn collector_id(mut self, collector_id: u64) -> Self {
+        self.collector_id = collector_id;
+        self
+    }
+
+    /// Accepts only `access_token`; other tokens get `401`. By default any
+    /// bearer token is accepted.
+    pub fn access_token(mut self, access_token: &str) -> Self {
+        self.access_token = Some(access_token.to_string());
+        self
+    }
+
+    /// Sets the secret webhooks are signed with.
+    pub fn webhook_secret(mut self, secret: &str) -> Self {
+        self.webhook_secret = secret.to_string();
+        self
+    }
+
+    /// Starts the simulator's HTTP server.
+    pub async fn start(self) -> Simulator {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::new(self.collector_id, self.access_token.clone())),
+            faults: Mutex::new(VecDeque::new()),
+        });
+        let server = MockServer::start().await;
+        Mock::given(any())
+            .respond_with(Handler(shared.clone()))
+            .mount(&server)
+            .await;
+
+        Simulator {
+            server,
+            shared,
+            collector_id: self.collector_id,
+            access_token: self
+                .access_token
+                .unwrap_or_else(|| DEFAULT_ACCESS_TOKEN.to_string()),
+            webhook_secret: self.webhook_secret,
+        }
+    }
+}
+
+/// A payment notification built by [`Simulator::webhook`].
+///
+/// Mercado Pago posts `body` to the notification URL with `data.id` and
+/// `type` in the query string, and signs it in the `x-signature` header:
+/// `ts=<timestamp>,v1=<hex HMAC-SHA256>` of
+/// `id:<data.id>;request-id:<x-request-id>;ts:<timestamp>;`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Webhook {
+    /// ID of the payment, as sent in `data.id`.
+    pub data_id: String,
+    /// Value of the `x-request-id` header.
+    pub request_id: String,
+    /// Signature timestamp, in seconds since the Unix epoch.
+    pub ts: u64,
+    /// Value of the `x-signature` header.
+    pub signature: String,
+    /// JSON body.
+    pub body: Value,
+}
+
+impl Webhook {
+    /// Returns the `content-type`, `x-request-id` and `x-signature` headers.
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        for (name, value) in [
+            ("x-request-id", &self.request_id),
+            ("x-signature", &self.signature),
+        ] {
+            if let Ok(value) = HeaderValue::from_str(value) {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+
+    /// Returns the query string, without the leading `?`.
+    pub fn query(&self) -> String {
+        format!("data.id={}&type=payment", self.data_id)
+    }
+}
+
+/// Computes the `x-signature` header value for a notification.
+fn sign(secret: &str, data_id: &str, request_id: &str, ts: u64) -> String {
+    let manifest = format!("id:{};request-id:{};ts:{};", data_id, request_id, ts);
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(manifest.as_bytes());
+    let digest: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("ts={},v1={}", ts, digest)
+}
@@ -0,0 +1,863 @@
+//! In-memory API state and request handling for the
+//! [`Simulator`](super::Simulator).
+use crate::client::new_idempotency_key;
+use crate::emvco::{EmvcoPayload, MerchantAccountInfo};
+use crate::models::common::{Paging, SearchResponse};
+use crate::models::instore::{
+    PosRequest, PosResponse, QrOrderRequest, QrOrderResponse, StoreRequest, StoreResponse,
+};
+use crate::models::payments::{
+    PayerResponse, PaymentRequest, PaymentResponse, TICKET_METHODS_WITH_ADDRESS, TransactionDetails,
+};
+use crate::models::preferences::{PreferenceRequest, PreferenceResponse};
+use crate::models::refunds::{RefundRequest, RefundResponse};
+use crate::models::users::User;
+use http::{HeaderMap, StatusCode};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Search parameters that are not field filters.
+const SEARCH_PARAMS: &[&str] = &[
+    "sort",
+    "criteria",
+    "limit",
+    "offset",
+    "range",
+    "begin_date",
+    "end_date",
+];
+
+/// Email of the buyer that pays QR orders and preferences.
+const TEST_BUYER_EMAIL: &str = "test_user_buyer@testuser.com";
+
+/// A response produced by the simulator.
+pub(crate) struct Reply {
+    pub(crate) status: StatusCode,
+    pub(crate) body: Value,
+}
+
+impl Reply {
+    fn new(status: StatusCode, body: &impl Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_value(body).unwrap_or(Value::Null),
+        }
+    }
+
+    fn ok(body: &impl Serialize) -> Self {
+        Self::new(StatusCode::OK, body)
+    }
+
+    fn created(body: &impl Serialize) -> Self {
+        Self::new(StatusCode::CREATED, body)
+    }
+
+    /// An error in the API's shape: `message`, `error`, `status` and `cause`.
+    pub(crate) fn error(status: StatusCode, message: &str, causes: &[Cause]) -> Self {
+        let error = match status {
+            StatusCode::BAD_REQUEST => "bad_request",
+            StatusCode::UNAUTHORIZED => "unauthorized",
+            StatusCode::FORBIDDEN => "forbidden",
+            StatusCode::NOT_FOUND => "not_found",
+            StatusCode::CONFLICT => "conflict",
+            StatusCode::TOO_MANY_REQUESTS => "too_many_requests",
+            _ => "internal_error",
+        };
+        let cause: Vec<Value> = causes
+            .iter()
+            .map(|c| match c.code {
+                Some(code) => json!({ "code": code, "description": c.description }),
+                None => json!({ "description": c.description }),
+            })
+            .collect();
+        Self {
+            status,
+            body: json!({
+                "message": message,
+                "error": error,
+                "status": status.as_u16(),
+                "cause": cause,
+            }),
+        }
+    }
+
+    fn bad_request(causes: Vec<Cause>) -> Self {
+        let message = causes
+            .first()
+            .map(|c| c.description.clone())
+            .unwrap_or_default();
+        Self::error(StatusCode::BAD_REQUEST, &message, &causes)
+    }
+
+    fn not_found(message: &str) -> Self {
+        Self::error(StatusCode::NOT_FOUND, message, &[])
+    }
+}
+
+/// A validation failure, reported in the `cause` array.
+pub(crate) struct Cause {
+    code: Option<u32>,
+    description: String,
+}
+
+impl Cause {
+    fn new(code: Option<u32>, description: impl Into<String>) -> Self {
+        Self {
+            code,
+            description: description.into(),
+        }
+    }
+}
+
+struct Payment {
+    response: PaymentResponse,
+    refunds: Vec<RefundResponse>,
+}
+
+/// Everything the simulated API knows about.
+pub(crate) struct State {
+    collector_id: u64,
+    access_token: Option<String>,
+    next_id: u64,
+    stores: Vec<StoreResponse>,
+    pos: Vec<PosResponse>,
+    /// The pending QR order of each POS, by POS external ID.
+    qr_orders: HashMap<String, QrOrderRequest>,
+    preferences: HashMap<String, PreferenceRequest>,
+    payments: BTreeMap<u64, Payment>,
+    /// Payment created for each `X-Idempotency-Key`.
+    idempotency_keys: HashMap<String, u64>,
+}
+
+impl State {
+    pub(crate) fn new(collector_id: u64, access_token: Option<String>) -> Self {
+        Self {
+            collector_id,
+            access_token,
+            next_id: 1_000_000_001,
+            stores: Vec::new(),
+            pos: Vec::new(),
+            qr_orders: HashMap::new(),
+            preferences: HashMap::new(),
+            payments: BTreeMap::new(),
+            idempotency_keys: HashMap::new(),
+        }
+    }
+
+    /// Routes a request to its endpoint.
+    pub(crate) fn handle(
+        &mut self,
+        method: &str,
+        path: &str,
+        query: &[(String, String)],
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Reply {
+        if !self.authorized(headers) {
+            return Reply::error(StatusCode::UNAUTHORIZED, "invalid access token", &[]);
+        }
+
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match (method, segments.as_slice()) {
+            ("GET", ["users", "me"]) => self.get_me(),
+            ("POST", ["users", user_id, "stores"]) => self.create_store(user_id, body),
+            ("GET", ["users", user_id, "stores", "search"]) => self.search_stores(user_id),
+            ("POST", ["pos"]) => self.create_pos(body),
+            ("GET", ["pos"]) => Reply::ok(&search_page(self.pos.clone(), query)),
+            (
+                "POST",
+                [
+                    "instore",
+                    "orders",
+                    "qr",
+                    "seller",
+                    "collectors",
+                    user_id,
+                    "pos",
+                    pos_id,
+                    "qrs",
+                ],
+            ) => self.create_qr_order(user_id, pos_id, body),
+            ("POST", ["checkout", "preferences"]) => self.create_preference(body),
+            ("POST", ["v1", "payments"]) => self.create_payment(headers, body),
+            ("GET", ["v1", "payments", "search"]) => self.search_payments(query),
+            ("GET", ["v1", "payments", id]) => match self.payment(id) {
+                Ok(payment) => Reply::ok(&payment.response),
+                Err(reply) => reply,
+            },
+            ("POST", ["v1", "payments", id, "refunds"]) => self.create_refund(id, body),
+            ("GET", ["v1", "payments", id, "refunds"]) => match self.payment(id) {
+                Ok(payment) => Reply::ok(&payment.refunds),
+                Err(reply) => reply,
+            },
+            _ => Reply::not_found(&format!("resource {} {} not found", method, path)),
+        }
+    }
+
+    fn authorized(&self, headers: &HeaderMap) -> bool {
+        let token = headers
+            .get(http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .unwrap_or_default();
+        match &self.access_token {
+            Some(expected) => token == expected,
+            None => !token.is_empty(),
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Checks that the path names the simulated collector.
+    fn check_user(&self, user_id: &str) -> Result<(), Reply> {
+        if user_id == self.collector_id.to_string() {
+            Ok(())
+        } else {
+            Err(Reply::error(
+                StatusCode::FORBIDDEN,
+                &format!("access token does not belong to user {}", user_id),
+                &[],
+            ))
+        }
+    }
+
+    fn get_me(&self) -> Reply {
+        Reply::ok(&User {
+            id: self.collector_id,
+            nickname: Some(format!("TESTUSER{}", self.collector_id)),
+            email: Some(format!("test_user_{}@testuser.com", self.collector_id)),
+            first_name: Some("Test".to_string()),
+            last_name: Some("Seller".to_string()),
+            site_id: Some("MLA".to_string()),
+            country_id: Some("AR".to_string()),
+        })
+    }
+
+    fn create_store(&mut self, user_id: &str, body: &[u8]) -> Reply {
+        if let Err(reply) = self.check_user(user_id) {
+            return reply;
+        }
+        let request: StoreRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(reply) => return reply,
+        };
+
+        let mut causes = Vec::new();
+        if request.name.trim().is_empty() {
+            causes.push(Cause::new(None, "name is required"));
+        }
+        if request.external_id.trim().is_empty() {
+            causes.push(Cause::new(None, "external_id is required"));
+        }
+        if !causes.is_empty() {
+            return Reply::bad_request(causes);
+        }
+        if self
+            .stores
+            .iter()
+            .any(|s| s.external_id.as_deref() == Some(&request.external_id))
+        {
+            return Reply::error(
+                StatusCode::CONFLICT,
+                &format!("store external_id {} already exists", request.external_id),
+                &[],
+            );
+        }
+
+        let store = StoreResponse {
+            id: self.next_id().to_string(),
+            name: request.name,
+            external_id: Some(request.external_id),
+            date_creation: Some(now()),
+            location: serde_json::to_value(&request.location).ok(),
+        };
+        self.stores.push(store.clone());
+        Reply::created(&store)
+    }
+
+    fn search_stores(&self, user_id: &str) -> Reply {
+        if let Err(reply) = self.check_user(user_id) {
+            return reply;
+        }
+        Reply::ok(&search_page(self.stores.clone(), &[]))
+    }
+
+    fn create_pos(&mut self, body: &[u8]) -> Reply {
+        let request: PosRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(reply) => return reply,
+        };
+
+        let mut causes = Vec::new();
+        if request.name.trim().is_empty() {
+            causes.push(Cause::new(None, "name is required"));
+        }
+        if request.external_id.trim().is_empty() {
+            causes.push(Cause::new(None, "external_id is required"));
+        }
+        if !self.stores.iter().any(|s| s.id == request.store_id) {
+            causes.push(Cause::new(
+                None,
+                format!("store {} not found", request.store_id),
+            ));
+        }
+        if !causes.is_empty() {
+            return Reply::bad_request(causes);
+        }
+        if self
+            .pos
+            .iter()
+            .any(|p| p.external_id.as_deref() == Some(&request.external_id))
+        {
+            return Reply::error(
+                StatusCode::CONFLICT,
+                &format!("pos external_id {} already exists", request.external_id),
+                &[],
+            );
+        }
+
+        let date = now();
+        let pos = PosResponse {
+            id: self.next_id(),
+            name: request.name,
+            external_id: Some(request.external_id),
+            store_id: request.store_id,
+            date_created: Some(date.clone()),
+            date_last_updated: Some(date),
+        };
+        self.pos.push(pos.clone());
+        Reply::created(&pos)
+    }
+
+    fn create_qr_order(&mut self, user_id: &str, pos_id: &str, body: &[u8]) -> Reply {
+        if let Err(reply) = self.check_user(user_id) {
+            return reply;
+        }
+        let request: QrOrderRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(reply) => return reply,
+        };
+        let Some(pos) = self
+            .pos
+            .iter()
+            .find(|p| p.external_id.as_deref() == Some(pos_id))
+        else {
+            return Reply::not_found(&format!("pos {} not found", pos_id));
+        };
+
+        let mut causes = Vec::new();
+        if request.items.is_empty() {
+            causes.push(Cause::new(None, "items are required"));
+        }
+        for item in &request.items {
+            if item.quantity <= 0 {
+                causes.push(Cause::new(
+                    None,
+                    format!("item {} must have a positive quantity", item.title),
+                ));
+            } else if cents(item.unit_price * item.quantity as f64) != cents(item.total_amount) {
+                causes.push(Cause::new(
+                    None,
+                    format!(
+                        "item {} total_amount must be unit_price * quantity",
+                        item.title
+                    ),
+                ));
+            }
+        }
+        let items_total: f64 = request.items.iter().map(|i| i.total_amount).sum();
+        if cents(items_total) != cents(request.total_amount) {
+            causes.push(Cause::new(
+                None,
+                "total_amount must be the sum of the items total_amount",
+            ));
+        }
+        if !causes.is_empty() {
+            return Reply::bad_request(causes);
+        }
+
+        let in_store_order_id = random_id();
+        let store = self.stores.iter().find(|s| s.id == pos.store_id);
+        let city = store
+            .and_then(|s| s.location.as_ref())
+            .and_then(|l| l.get("city_name"))
+            .and_then(Value::as_str)
+            .unwrap_or("Buenos Aires");
+        let payload = EmvcoPayload {
+            payload_format_indicator: "01".to_string(),
+            point_of_initiation_method: Some("12".to_string()),
+            merchant_account_info: vec![MerchantAccountInfo {
+                tag: 43,
+                value: format!(
+                    "0016COM.MERCADOLIBRE02{:02}{}",
+                    in_store_order_id.len(),
+                    in_store_order_id
+                ),
+            }],
+            merchant_category_code: "0000".to_string(),
+            transaction_currency: "032".to_string(),
+            transaction_amount: Some(format!("{:.2}", request.total_amount)),
+            country_code: "AR".to_string(),
+            merchant_name: truncate(store.map_or("Mercado Pago", |s| &s.name), 25),
+            merchant_city: truncate(city, 15),
+            postal_code: None,
+            additional_data: None,
+            other_fields: Vec::new(),
+            crc: 0,
+        };
+
+        self.qr_orders.insert(pos_id.to_string(), request);
+        Reply::created(&QrOrderResponse {
+            qr_data: payload.encode(),
+            in_store_order_id,
+        })
+    }
+
+    fn create_preference(&mut self, body: &[u8]) -> Reply {
+        let request: PreferenceRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(reply) => return reply,
+        };
+
+        let mut causes = Vec::new();
+        if request.items.is_empty() {
+            causes.push(Cause::new(None, "items are required"));
+        }
+        for item in &request.items {
+            if item.quantity <= 0 {
+                causes.push(Cause::new(
+                    None,
+                    format!("item {} must have a positive quantity", item.title),
+                ));
+            }
+            if item.unit_price <= 0.0 {
+                causes.push(Cause::new(
+                    None,
+                    format!("item {} must have a positive unit_price", item.title),
+                ));
+            }
+        }
+        if !causes.is_empty() {
+            return Reply::bad_request(causes);
+        }
+
+        let id = format!("{}-{}", self.collector_id, random_id());
+        let response = PreferenceResponse {
+            id: id.clone(),
+            items: request.items.clone(),
+            init_point: format!(
+                "https://www.mercadopago.com.ar/checkout/v1/redirect?pref_id={}",
+                id
+            ),
+            sandbox_init_point: format!(
+                "https://sandbox.mercadopago.com.ar/checkout/v1/redirect?pref_id={}",
+                id
+            ),
+            date_created: Some(now()),
+        };
+        self.preferences.insert(id, request);
+        Reply::created(&response)
+    }
+
+    fn create_payment(&mut self, headers: &HeaderMap, body: &[u8]) -> Reply {
+        let key = headers
+            .get("x-idempotency-key")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        if let Some(id) = key.as_ref().and_then(|k| self.idempotency_keys.get(k)) {
+            return Reply::created(&self.payments[id].response);
+        }
+
+        let request: PaymentRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(reply) => return reply,
+        };
+
+        let mut causes = Vec::new();
+        if request.transaction_amount <= 0.0 {
+            causes.push(Cause::new(Some(4037), "Invalid transaction_amount"));
+        }
+        if request.payment_method_id.trim().is_empty() {
+            causes.push(Cause::new(None, "payment_method_id is required"));
+        }
+        if !request.payer.email.contains('@') {
+            causes.push(Cause::new(Some(4050), "payer.email must be a valid email"));
+        }
+        if TICKET_METHODS_WITH_ADDRESS.contains(&request.payment_method_id.as_str()) {
+            let missing = request.payer.missing_boleto_fields();
+            if !missing.is_empty() {
+                causes.push(Cause::new(
+                    None,
+                    format!(
+                        "Payer is missing fields required by {}: {}",
+                        request.payment_method_id,
+                        missing.join(", ")
+                    ),
+                ));
+            }
+        }
+        if !causes.is_empty() {
+            return Reply::bad_request(causes);
+        }
+
+        let id = self.insert_payment(request);
+        if let Some(key) = key {
+            self.idempotency_keys.insert(key, id);
+        }
+        Reply::created(&self.payments[&id].response)
+    }
+
+    /// Stores a pending payment for `request` and returns its ID.
+    fn insert_payment(&mut self, request: PaymentRequest) -> u64 {
+        let id = self.next_id();
+        let date = now();
+        let payment_type_id = payment_type(&request.payment_method_id);
+        let status_detail = if payment_type_id.ends_with("_card") {
+            "pending_contingency"
+        } else {
+            "pending_waiting_payment"
+        };
+        let response = PaymentResponse {
+            id,
+            status: "pending".to_string(),
+            status_detail: Some(status_detail.to_string()),
+            transaction_amount: request.transaction_amount,
+            date_created: Some(date.clone()),
+            external_reference: request.external_reference,
+            payment_method_id: Some(request.payment_method_id),
+            payment_type_id: Some(payment_type_id.to_string()),
+            currency_id: Some("ARS".to_string()),
+            description: request.description,
+            installments: Some(request.installments.unwrap_or(1)),
+            net_received_amount: None,
+            captured: Some(true),
+            date_approved: None,
+            date_last_updated: Some(date),
+            live_mode: Some(false),
+            payer: Some(PayerResponse {
+                id: None,
+                email: Some(request.payer.email),
+                identification: request.payer.identification,
+                payer_type: None,
+            }),
+            fee_details: None,
+            three_ds_info: None,
+            point_of_interaction: None,
+            transaction_details: None,
+            date_of_expiration: request.date_of_expiration,
+        };
+        self.payments.insert(
+            id,
+            Payment {
+                response,
+                refunds: Vec::new(),
+            },
+        );
+        id
+    }
+
+    fn payment(&self, id: &str) -> Result<&Payment, Reply> {
+        id.parse::<u64>()
+            .ok()
+            .and_then(|id| self.payments.get(&id))
+            .ok_or_else(|| Reply::not_found(&format!("Payment not found: {}", id)))
+    }
+
+    fn search_payments(&self, query: &[(String, String)]) -> Reply {
+        let filters: Vec<(&str, &str)> = query
+            .iter()
+            .filter(|(k, _)| !SEARCH_PARAMS.contains(&k.as_str()))
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        let mut results: Vec<PaymentResponse> = self
+            .payments
+            .values()
+            .map(|p| p.response.clone())
+            .filter(|p| {
+                let value = serde_json::to_value(p).unwrap_or(Value::Null);
+                filters
+                    .iter()
+                    .all(|(field, expected)| field_matches(&value, field, expected))
+            })
+            .collect();
+        if query
+            .iter()
+            .any(|(k, v)| k == "criteria" && v.eq_ignore_ascii_case("desc"))
+        {
+            results.reverse();
+        }
+        Reply::ok(&search_page(results, query))
+    }
+
+    fn create_refund(&mut self, id: &str, body: &[u8]) -> Reply {
+        let request: RefundRequest = if body.is_empty() {
+            RefundRequest::default()
+        } else {
+            match parse_body(body) {
+                Ok(request) => request,
+                Err(reply) => return reply,
+            }
+        };
+        let refund_id = self.next_id;
+        let payment = match id
+            .parse::<u64>()
+            .ok()
+            .and_then(|id| self.payments.get_mut(&id))
+        {
+            Some(payment) => payment,
+            None => return Reply::not_found(&format!("Payment not found: {}", id)),
+        };
+
+        if payment.response.status != "approved" {
+            return Reply::bad_request(vec![Cause::new(
+                None,
+                format!(
+                    "Payment in status {} cannot be refunded",
+                    payment.response.status
+                ),
+            )]);
+        }
+        let refunded: f64 = payment.refunds.iter().map(|r| r.amount).sum();
+        let available = payment.response.transaction_amount - refunded;
+        let amount = request.amount.unwrap_or(available);
+        if amount <= 0.0 {
+            return Reply::bad_request(vec![Cause::new(None, "Invalid refund amount")]);
+        }
+        if cents(amount) > cents(available) {
+            return Reply::bad_request(vec![Cause::new(
+                None,
+                format!(
+                    "Refund amount {} exceeds the available amount {}",
+                    amount, available
+                ),
+            )]);
+        }
+
+        let date = now();
+        let refund = RefundResponse {
+            id: refund_id,
+            payment_id: payment.response.id,
+            amount,
+            status: "approved".to_string(),
+            date_created: Some(date.clone()),
+        };
+        payment.refunds.push(refund.clone());
+        if cents(amount) == cents(available) {
+            payment.response.status = "refunded".to_string();
+            payment.response.status_detail = Some("refunded".to_string());
+        } else {
+            payment.response.status_detail = Some("partially_refunded".to_string());
+        }
+        payment.response.date_last_updated = Some(date);
+        self.next_id += 1;
+        Reply::created(&refund)
+    }
+
+    /// Moves a pending payment to `status`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payment does not exist or is not pending.
+    pub(crate) fn settle_payment(&mut self, id: u64, status: &str, status_detail: &str) {
+        let payment = self
+            .payments
+            .get_mut(&id)
+            .unwrap_or_else(|| panic!("payment {} does not exist", id));
+        let response = &mut payment.response;
+        assert!(
+            matches!(response.status.as_str(), "pending" | "in_process"),
+            "payment {} is {}, not pending",
+            id,
+            response.status
+        );
+
+        let date = now();
+        response.status = status.to_string();
+        response.status_detail = Some(status_detail.to_string());
+        response.date_last_updated = Some(date.clone());
+        if status == "approved" {
+            response.date_approved = Some(date);
+            response.net_received_amount = Some(response.transaction_amount);
+            response.transaction_details = Some(TransactionDetails {
+                net_received_amount: Some(response.transaction_amount),
+                total_paid_amount: Some(response.transaction_amount),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Creates a pending payment for the QR order of the POS `pos_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the POS has no pending QR order.
+    pub(crate) fn pay_qr_order(&mut self, pos_id: &str) -> u64 {
+        let order = self
+            .qr_orders
+            .remove(pos_id)
+            .unwrap_or_else(|| panic!("POS {} has no pending QR order", pos_id));
+        self.insert_payment(buyer_payment(
+            order.total_amount,
+            order.external_reference,
+            order.title,
+        ))
+    }
+
+    /// Creates a pending payment for the preference `preference_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the preference does not exist.
+    pub(crate) fn pay_preference(&mut self, preference_id: &str) -> u64 {
+        let preference = self
+            .preferences
+            .get(preference_id)
+            .unwrap_or_else(|| panic!("preference {} does not exist", preference_id));
+        let total = preference
+            .items
+            .iter()
+            .map(|i| i.unit_price * i.quantity as f64)
+            .sum();
+        let request = buyer_payment(
+            total,
+            preference.external_reference.clone(),
+            preference.items.first().map(|i| i.title.clone()),
+        );
+        self.insert_payment(request)
+    }
+
+    pub(crate) fn get_payment(&self, id: u64) -> Option<PaymentResponse> {
+        self.payments.get(&id).map(|p| p.response.clone())
+    }
+}
+
+/// The payment the test buyer makes with their Mercado Pago account.
+fn buyer_payment(
+    amount: f64,
+    external_reference: Option<String>,
+    description: Option<String>,
+) -> PaymentRequest {
+    PaymentRequest {
+        transaction_amount: amount,
+        payment_method_id: "account_money".to_string(),
+        description,
+        external_reference,
+        payer: crate::models::payments::Payer {
+            email: TEST_BUYER_EMAIL.to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, Reply> {
+    serde_json::from_slice(body).map_err(|e| {
+        Reply::error(
+            StatusCode::BAD_REQUEST,
+            &format!("invalid request body: {}", e),
+            &[],
+        )
+    })
+}
+
+/// Applies `offset` and `limit` (default 30) to `results`.
+fn search_page<T>(results: Vec<T>, query: &[(String, String)]) -> SearchResponse<T> {
+    let param = |name: &str, default: u32| {
+        query
+            .iter()
+            .find(|(k, _)| k == name)
+            .and_then(|(_, v)| v.parse().ok())
+            .unwrap_or(default)
+    };
+    let offset = param("offset", 0);
+    let limit = param("limit", 30);
+    let total = results.len() as u32;
+    SearchResponse {
+        paging: Paging {
+            total,
+            offset,
+            limit,
+        },
+        results: results
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect(),
+    }
+}
+
+/// Whether the dotted `field` of `value` (e.g. `payer.email`) equals `expected`.
+fn field_matches(value: &Value, field: &str, expected: &str) -> bool {
+    let pointer = format!("/{}", field.replace('.', "/"));
+    match value.pointer(&pointer) {
+        Some(Value::String(s)) => s == expected,
+        Some(Value::Number(n)) => n.to_string() == expected,
+        Some(Value::Bool(b)) => b.to_string() == expected,
+        _ => false,
+    }
+}
+
+fn payment_type(payment_method_id: &str) -> &'static str {
+    match payment_method_id {
+        "account_money" => "account_money",
+        "pix" => "bank_transfer",
+        "bolbradesco" | "pec" | "rapipago" | "pagofacil" | "oxxo" => "ticket",
+        "debvisa" | "debmaster" | "maestro" | "debcabal" => "debit_card",
+        _ => "credit_card",
+    }
+}
+
+fn cents(amount: f64) -> i64 {
+    (amount * 100.0).round() as i64
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    value.chars().take(max_chars).collect()
+}
+
+/// A random UUID for simulated IDs.
+pub(crate) fn random_id() -> String {
+    new_idempotency_key().expect("OS random number generator unavailable")
+}
+
+/// Seconds since the Unix epoch.
+pub(crate) fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// The current time in the API's ISO 8601 format, in UTC.
+pub(crate) fn now() -> String {
+    let secs = unix_time();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.000Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
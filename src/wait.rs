@@ -0,0 +1,308 @@
+//! Waiting for a payment to reach a final status.
+//!
+//! Point-of-sale flows usually create a QR order and then wait until the buyer
+//! pays. [`MercadoPagoClient::wait_for_payment`] implements that loop: it polls
+//! the payment search endpoint with exponential backoff, stops as soon as a
+//! payment for the external reference reaches a final status and honours a
+//! deadline and an optional cancellation token.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mercadopago_sdk::MercadoPagoClient;
+//! use mercadopago_sdk::wait::{PaymentOutcome, WaitOptions};
+//! use std::time::Duration;
+//!
+//! # async fn run() -> Result<(), mercadopago_sdk::Error> {
+//! let client = MercadoPagoClient::builder("YOUR_ACCESS_TOKEN").build()?;
+//!
+//! let options = WaitOptions::new().timeout(Duration::from_secs(120));
+//! match client.wait_for_payment("ORDER-1234", options).await? {
+//!     PaymentOutcome::Approved(payment) => println!("Paid: {}", payment.id),
+//!     PaymentOutcome::Rejected(payment) => println!("Rejected: {}", payment.status),
+//!     PaymentOutcome::TimedOut => println!("No payment in time"),
+//!     PaymentOutcome::Cancelled => println!("Cancelled by the operator"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::client::MercadoPagoClient;
+use crate::error::Error;
+use crate::models::payments::{PaymentResponse, PaymentStatus};
+use std::future::pending;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::{Instant, sleep_until, timeout_at};
+use tokio_util::sync::CancellationToken;
+
+/// Result of [`MercadoPagoClient::wait_for_payment`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentOutcome {
+    /// A payment for the external reference was approved.
+    Approved(PaymentResponse),
+    /// Every payment for the target reached a final status other than
+    /// approved (rejected, cancelled, refunded or charged back); holds the
+    /// most recent one.
+    Rejected(PaymentResponse),
+    /// The deadline elapsed before a final status was observed.
+    TimedOut,
+    /// The cancellation token was triggered.
+    Cancelled,
+}
+
+/// Options for [`MercadoPagoClient::wait_for_payment`].
+///
+/// Polling starts at `initial_interval` and grows by `multiplier` after every
+/// attempt, up to `max_interval`.
+#[derive(Debug)]
+pub struct WaitOptions {
+    timeout: Duration,
+    initial_interval: Duration,
+    max_interval: Duration,
+    multiplier: f64,
+    cancellation: Option<CancellationToken>,
+    notifications: Option<broadcast::Receiver<u64>>,
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(10),
+            multiplier: 1.5,
+            cancellation: None,
+            notifications: None,
+        }
+    }
+}
+
+impl WaitOptions {
+    /// Creates options with the default values: a 5 minute deadline and polling
+    /// from every second up to every 10 seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum time to wait for a final status. Defaults to 5 minutes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the delay before the second poll. Defaults to 1 second.
+    pub fn initial_interval(mut self, interval: Duration) -> Self {
+        self.initial_interval = interval;
+        self
+    }
+
+    /// Sets the upper bound for the delay between polls. Defaults to 10 seconds.
+    pub fn max_interval(mut self, interval: Duration) -> Self {
+        self.max_interval = interval;
+        self
+    }
+
+    /// Sets the factor applied to the delay after every poll. Defaults to 1.5.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Stops waiting with [`PaymentOutcome::Cancelled`] when `token` is cancelled.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Listens for payment IDs pushed by an in-process webhook handler.
+    ///
+    /// Every ID received is looked up right away, so the outcome is known as
+    /// soon as the notification arrives. Polling keeps running as a fallback
+    /// in case a notification is lost.
+    pub fn notifications(mut self, receiver: broadcast::Receiver<u64>) -> Self {
+        self.notifications = Some(receiver);
+        self
+    }
+
+    fn next_interval(&self, current: Duration) -> Duration {
+        current.mul_f64(self.multiplier).min(self.max_interval)
+    }
+}
+
+impl MercadoPagoClient {
+    /// Waits until a payment for `external_reference` reaches a final status.
+    ///
+    /// Transient failures (network errors, 429 and 5xx responses) are ignored
+    /// and the search is retried on the next poll.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API rejects the search with a non-transient
+    /// error (e.g., an invalid access token).
+    pub async fn wait_for_payment(
+        &self,
+        external_reference: &str,
+        options: WaitOptions,
+    ) -> Result<PaymentOutcome, Error> {
+        self.wait_for(Target::ExternalReference(external_reference), options)
+            .await
+    }
+
+    /// Waits until the payment `payment_id` reaches a final status.
+    ///
+    /// Use it after the buyer completes a 3-D Secure challenge (see
+    /// [`PaymentResponse::challenge`]): the payment stays `pending` until the
+    /// issuer reports the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API rejects the lookup with a non-transient
+    /// error (e.g., the payment does not exist).
+    pub async fn wait_for_payment_id(
+        &self,
+        payment_id: u64,
+        options: WaitOptions,
+    ) -> Result<PaymentOutcome, Error> {
+        self.wait_for(Target::PaymentId(payment_id), options).await
+    }
+
+    async fn wait_for(
+        &self,
+        target: Target<'_>,
+        options: WaitOptions,
+    ) -> Result<PaymentOutcome, Error> {
+        let deadline = Instant::now() + options.timeout;
+        let token = options.cancellation.clone();
+
+        let cancelled = async {
+            match token {
+                Some(token) => token.cancelled_owned().await,
+                None => pending().await,
+            }
+        };
+
+        tokio::select! {
+            res = timeout_at(deadline, self.poll_until_final(target, options)) => {
+                res.unwrap_or(Ok(PaymentOutcome::TimedOut))
+            }
+            _ = cancelled => Ok(PaymentOutcome::Cancelled),
+        }
+    }
+
+    async fn poll_until_final(
+        &self,
+        target: Target<'_>,
+        mut options: WaitOptions,
+    ) -> Result<PaymentOutcome, Error> {
+        let mut interval = options.initial_interval;
+
+        loop {
+            match self.fetch(target).await {
+                Ok(payments) => {
+                    if let Some(outcome) = outcome_of(payments) {
+                        return Ok(outcome);
+                    }
+                }
+                Err(e) if e.is_retryable() => {
+                    #[cfg(feature = "logging")]
+                    tracing::warn!("Transient error while waiting for payment: {}", e);
+                }
+                Err(e) => return Err(e),
+            }
+
+            let wake = Instant::now() + interval;
+            interval = options.next_interval(interval);
+
+            while let Some(receiver) = options.notifications.as_mut() {
+                let payment_id = tokio::select! {
+                    _ = sleep_until(wake) => break,
+                    msg = receiver.recv() => msg,
+                };
+
+                match payment_id {
+                    Ok(id) => match self.get_payment(id).await {
+                        Ok(payment) if target.matches(&payment) => {
+                            match payment.payment_status() {
+                                PaymentStatus::Approved => {
+                                    return Ok(PaymentOutcome::Approved(payment));
+                                }
+                                // Other payments may still be in flight: poll them all.
+                                status if status.is_final() => break,
+                                _ => {}
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(e) if e.is_retryable() => {}
+                        Err(e) => return Err(e),
+                    },
+                    // Missed notifications: poll right away.
+                    Err(RecvError::Lagged(_)) => break,
+                    Err(RecvError::Closed) => options.notifications = None,
+                }
+            }
+
+            if options.notifications.is_none() {
+                sleep_until(wake).await;
+            }
+        }
+    }
+}
+
+/// The payment(s) a wait is watching.
+#[derive(Clone, Copy)]
+enum Target<'a> {
+    ExternalReference(&'a str),
+    PaymentId(u64),
+}
+
+impl Target<'_> {
+    fn matches(&self, payment: &PaymentResponse) -> bool {
+        match *self {
+            Target::ExternalReference(reference) => {
+                payment.external_reference.as_deref() == Some(reference)
+            }
+            Target::PaymentId(id) => payment.id == id,
+        }
+    }
+}
+
+impl MercadoPagoClient {
+    async fn fetch(&self, target: Target<'_>) -> Result<Vec<PaymentResponse>, Error> {
+        match target {
+            Target::ExternalReference(reference) => {
+                Ok(self.search_payments(reference).await?.results)
+            }
+            Target::PaymentId(id) => Ok(vec![self.get_payment(id).await?]),
+        }
+    }
+}
+
+/// Decides the wait from every payment seen for the target.
+///
+/// An approved payment wins. Any other final status only ends the wait once
+/// no payment for the target is still in flight, since a buyer whose card was
+/// rejected often retries with another one; the most recent of them (highest
+/// ID) is reported.
+fn outcome_of(payments: Vec<PaymentResponse>) -> Option<PaymentOutcome> {
+    let mut in_flight = false;
+    let mut latest_final: Option<PaymentResponse> = None;
+    for payment in payments {
+        match payment.payment_status() {
+            PaymentStatus::Approved => return Some(PaymentOutcome::Approved(payment)),
+            PaymentStatus::Rejected
+            | PaymentStatus::Cancelled
+            | PaymentStatus::Refunded
+            | PaymentStatus::ChargedBack => {
+                if latest_final.as_ref().is_none_or(|p| p.id < payment.id) {
+                    latest_final = Some(payment);
+                }
+            }
+            _ => in_flight = true,
+        }
+    }
+    if in_flight {
+        return None;
+    }
+    latest_final.map(PaymentOutcome::Rejected)
+}
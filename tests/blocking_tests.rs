@@ -0,0 +1,97 @@
+#![cfg(feature = "blocking")]
+
+use mercadopago_sdk::blocking::MercadoPagoClient;
+use mercadopago_sdk::models::payments::{Payer, PaymentRequest};
+use mercadopago_sdk::{Error, ErrorKind};
+use serde_json::json;
+use tokio::runtime::Runtime;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Starts a mock server on its own runtime, outside the blocking client's.
+fn mock_server() -> (Runtime, MockServer) {
+    let runtime = Runtime::new().unwrap();
+    let server = runtime.block_on(MockServer::start());
+    (runtime, server)
+}
+
+fn payment() -> serde_json::Value {
+    json!({ "id": 1, "status": "approved", "transaction_amount": 10.0 })
+}
+
+#[test]
+fn test_blocking_client_typed_methods() {
+    let (runtime, server) = mock_server();
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&server.uri())
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        Mock::given(method("GET"))
+            .and(path("/v1/payments/1"))
+            .and(header("Authorization", "Bearer TEST_TOKEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/payments"))
+            .and(body_partial_json(json!({ "transaction_amount": 10.0 })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(payment()))
+            .mount(&server)
+            .await;
+    });
+
+    let payment = client.get_payment(1).unwrap();
+    assert_eq!(payment.id, 1);
+
+    let request = PaymentRequest {
+        transaction_amount: 10.0,
+        payment_method_id: "pix".to_string(),
+        payer: Payer {
+            email: "buyer@example.com".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let created = client.clone().create_payment(request).unwrap();
+    assert_eq!(created.status, payment.status);
+    assert_eq!(client.stats().requests, 2);
+}
+
+#[test]
+fn test_blocking_client_retries_and_errors() {
+    let (runtime, server) = mock_server();
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&server.uri())
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        Mock::given(method("GET"))
+            .and(path("/v1/payments/1"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/payments/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/payments/2"))
+            .respond_with(
+                ResponseTemplate::new(404).set_body_json(json!({ "message": "Payment not found" })),
+            )
+            .mount(&server)
+            .await;
+    });
+
+    client.get_payment(1).unwrap();
+    assert_eq!(client.stats().retries, 1);
+
+    let err = client.get_payment(2).unwrap_err();
+    assert!(matches!(err, Error::ApiError(ref api) if api.status == 404));
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
@@ -0,0 +1,222 @@
+use async_trait::async_trait;
+use mercadopago_sdk::client::breaker::{CircuitBreakerConfig, CircuitState, EndpointGroup};
+use mercadopago_sdk::credentials::{CredentialsProvider, SecretString};
+use mercadopago_sdk::{Error, ErrorKind, MercadoPagoClient};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup(cooldown: Duration) -> (MockServer, MercadoPagoClient) {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .circuit_breaker(
+            CircuitBreakerConfig::new()
+                .failure_threshold(2)
+                .cooldown(cooldown),
+        )
+        .build()
+        .unwrap();
+    (mock_server, client)
+}
+
+fn payment() -> serde_json::Value {
+    json!({ "id": 1, "status": "approved", "transaction_amount": 10.0 })
+}
+
+#[tokio::test]
+async fn test_circuit_opens_after_consecutive_failures() {
+    let (mock_server, client) = setup(Duration::from_secs(60)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..2 {
+        let err = client.get_payment(1).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Server);
+    }
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Open
+    );
+
+    // The third request fails fast without reaching the server.
+    match client.get_payment(1).await.unwrap_err() {
+        Error::CircuitOpen { group, retry_after } => {
+            assert_eq!(group, EndpointGroup::Payments);
+            assert!(retry_after > Duration::from_secs(50));
+        }
+        other => panic!("Expected CircuitOpen, got {:?}", other),
+    }
+
+    // Other groups and clones are unaffected / shared respectively.
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Preferences),
+        CircuitState::Closed
+    );
+    assert_eq!(
+        client.clone().circuit_state(EndpointGroup::Payments),
+        CircuitState::Open
+    );
+}
+
+#[tokio::test]
+async fn test_client_errors_do_not_open_circuit() {
+    let (mock_server, client) = setup(Duration::from_secs(60)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..3 {
+        let err = client.get_payment(1).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Closed
+    );
+}
+
+#[tokio::test]
+async fn test_half_open_probe_closes_circuit() {
+    let (mock_server, client) = setup(Duration::from_millis(100)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..2 {
+        client.get_payment(1).await.unwrap_err();
+    }
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Open
+    );
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::HalfOpen
+    );
+
+    mock_server.reset().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+        .mount(&mock_server)
+        .await;
+
+    let payment = client.get_payment(1).await.unwrap();
+    assert_eq!(payment.id, 1);
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Closed
+    );
+}
+
+#[tokio::test]
+async fn test_failed_probe_reopens_circuit() {
+    let (mock_server, client) = setup(Duration::from_millis(100)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..2 {
+        client.get_payment(1).await.unwrap_err();
+    }
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    // A single failed probe is enough to open the circuit again.
+    let err = client.get_payment(1).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Server);
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Open
+    );
+    assert!(matches!(
+        client.get_payment(1).await,
+        Err(Error::CircuitOpen { .. })
+    ));
+}
+
+/// Fails before any request is sent.
+struct UnavailableCredentials;
+
+#[async_trait]
+impl CredentialsProvider for UnavailableCredentials {
+    async fn token(&self) -> Result<SecretString, Error> {
+        Err(Error::Internal("token source unavailable".to_string()))
+    }
+}
+
+#[tokio::test]
+async fn test_probe_without_response_keeps_circuit_half_open() {
+    let (mock_server, client) = setup(Duration::from_millis(100)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..2 {
+        client.get_payment(1).await.unwrap_err();
+    }
+    tokio::time::sleep(Duration::from_millis(150)).await;
+
+    // The probe fails inside the SDK: the circuit neither closes nor reopens.
+    let unauthenticated = client.with_credentials(Arc::new(UnavailableCredentials));
+    let err = unauthenticated.get_payment(1).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Internal);
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::HalfOpen
+    );
+
+    // The next request may probe right away.
+    let err = client.get_payment(1).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Server);
+    assert_eq!(
+        client.circuit_state(EndpointGroup::Payments),
+        CircuitState::Open
+    );
+}
+
+#[test]
+fn test_endpoint_groups() {
+    assert_eq!(
+        EndpointGroup::for_path("/v1/payments/123/refunds"),
+        EndpointGroup::Payments
+    );
+    assert_eq!(
+        EndpointGroup::for_path("/checkout/preferences"),
+        EndpointGroup::Preferences
+    );
+    assert_eq!(
+        EndpointGroup::for_path("/instore/qr/seller/collectors/1/pos/CAJA/orders"),
+        EndpointGroup::Instore
+    );
+    assert_eq!(
+        EndpointGroup::for_path("/users/1/stores/search"),
+        EndpointGroup::Instore
+    );
+    assert_eq!(
+        EndpointGroup::for_path("https://api.mercadopago.com/v1/orders/ORD1"),
+        EndpointGroup::Orders
+    );
+    assert_eq!(EndpointGroup::for_path("/users/me"), EndpointGroup::Other);
+}
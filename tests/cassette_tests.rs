@@ -0,0 +1,159 @@
+#![cfg(feature = "testing")]
+
+use mercadopago_sdk::client::new_idempotency_key;
+use mercadopago_sdk::models::payments::{Identification, Payer, PaymentRequest};
+use mercadopago_sdk::testing::Simulator;
+use mercadopago_sdk::testing::cassette::{Cassette, Mode};
+use mercadopago_sdk::{Error, MercadoPagoClient};
+use std::path::PathBuf;
+
+/// A client that fails any request that reaches the network.
+fn offline_client(cassette: &Cassette) -> MercadoPagoClient {
+    MercadoPagoClient::builder("TEST-REPLAY")
+        .base_url("http://127.0.0.1:9")
+        .cassette(cassette.clone())
+        .build()
+        .unwrap()
+}
+
+fn cassette_path() -> PathBuf {
+    std::env::temp_dir()
+        .join("mercadopago-cassettes")
+        .join(format!("{}.json", new_idempotency_key().unwrap()))
+}
+
+fn payment_request(amount: f64) -> PaymentRequest {
+    PaymentRequest::pix(
+        amount,
+        Payer {
+            email: "john.doe@gmail.com".to_string(),
+            identification: Some(Identification {
+                id_type: "CPF".to_string(),
+                number: "19119119100".to_string(),
+            }),
+            ..Default::default()
+        },
+    )
+    .with_date_of_expiration("2026-12-31T23:59:59.000-03:00")
+}
+
+/// Records a payment creation and two reads against a simulator.
+async fn record(path: &PathBuf) -> u64 {
+    let sim = Simulator::builder()
+        .access_token("TEST-SECRET")
+        .start()
+        .await;
+    let cassette = Cassette::record(path);
+    let client = MercadoPagoClient::builder("TEST-SECRET")
+        .base_url(&sim.uri())
+        .cassette(cassette.clone())
+        .build()
+        .unwrap();
+
+    let payment = client.create_payment(payment_request(100.0)).await.unwrap();
+    // The caller sees the live response; only the file is masked.
+    assert_eq!(
+        payment.payer.unwrap().email.as_deref(),
+        Some("john.doe@gmail.com")
+    );
+    client.get_payment(payment.id).await.unwrap();
+    sim.approve_payment(payment.id);
+    client.get_payment(payment.id).await.unwrap();
+
+    assert_eq!(cassette.mode(), Mode::Record);
+    assert_eq!(cassette.interactions().len(), 3);
+    payment.id
+}
+
+#[tokio::test]
+async fn test_cassette_records_masked_traffic() {
+    let path = cassette_path();
+    let id = record(&path).await;
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("TEST-SECRET"));
+    assert!(!contents.contains("john.doe@gmail.com"));
+    assert!(!contents.contains("19119119100"));
+    assert!(contents.contains("j***@gmail.com"));
+
+    let interactions = Cassette::replay(&path).unwrap().interactions();
+    assert_eq!(interactions[0].request.method, "POST");
+    assert_eq!(interactions[0].request.path, "/v1/payments");
+    assert_eq!(interactions[0].response.status, 201);
+    assert_eq!(interactions[2].request.path, format!("/v1/payments/{}", id));
+    assert_eq!(
+        interactions[2].response.body.as_ref().unwrap()["status"],
+        "approved"
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_cassette_replays_offline() {
+    let path = cassette_path();
+    let id = record(&path).await;
+
+    let cassette = Cassette::replay(&path).unwrap();
+    let client = offline_client(&cassette);
+
+    let payment = client.create_payment(payment_request(100.0)).await.unwrap();
+    assert_eq!(payment.id, id);
+    assert_eq!(payment.status, "pending");
+    assert_eq!(client.get_payment(id).await.unwrap().status, "pending");
+    assert_eq!(client.get_payment(id).await.unwrap().status, "approved");
+    assert_eq!(cassette.remaining(), 0);
+
+    let err = client.get_payment(id).await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("all 3 recorded requests were replayed"),
+        "{}",
+        err
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_cassette_mismatch_shows_diff() {
+    let path = cassette_path();
+    let id = record(&path).await;
+
+    let cassette = Cassette::replay(&path).unwrap();
+    let client = offline_client(&cassette);
+
+    let err = client
+        .create_payment(payment_request(150.0))
+        .await
+        .unwrap_err();
+    let Error::Internal(message) = err else {
+        panic!("expected a cassette mismatch, got {:?}", err);
+    };
+    assert!(message.contains("request #1 does not match the recording"));
+    assert!(message.contains("  POST /v1/payments\n"));
+    assert!(
+        message.contains("-     \"transaction_amount\": 100.0"),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("+     \"transaction_amount\": 150.0"),
+        "{}",
+        message
+    );
+    // Unchanged fields far from the change are elided.
+    assert!(!message.contains("date_of_expiration"), "{}", message);
+    assert_eq!(cassette.remaining(), 3);
+
+    client.create_payment(payment_request(100.0)).await.unwrap();
+    let err = client.get_payment(id + 1).await.unwrap_err();
+    assert!(
+        err.to_string().contains(&format!(
+            "- GET /v1/payments/{}\n+ GET /v1/payments/{}",
+            id,
+            id + 1
+        )),
+        "{}",
+        err
+    );
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,128 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::error::Error;
+use mercadopago_sdk::models::chargebacks::DocumentationFile;
+use serde_json::json;
+use wiremock::matchers::{header_regex, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn chargeback_body() -> serde_json::Value {
+    json!({
+        "id": "206000000001",
+        "payments": [123456],
+        "currency": "BRL",
+        "amount": 350.0,
+        "coverage_applied": false,
+        "coverage_elegible": true,
+        "documentation_required": true,
+        "documentation_status": "pending",
+        "documentation": [],
+        "date_documentation_deadline": "2026-10-25T23:59:59.000-03:00",
+        "date_created": "2026-10-15T10:00:00.000-03:00",
+        "live_mode": true
+    })
+}
+
+#[tokio::test]
+async fn test_get_chargeback() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/chargebacks/206000000001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chargeback_body()))
+        .mount(&mock_server)
+        .await;
+
+    let chargeback = client.get_chargeback("206000000001").await.unwrap();
+    assert_eq!(chargeback.payments, vec![123456]);
+    assert_eq!(chargeback.amount, 350.0);
+    assert_eq!(chargeback.coverage_applied, Some(false));
+    assert_eq!(chargeback.documentation_required, Some(true));
+    assert_eq!(
+        chargeback.date_documentation_deadline.as_deref(),
+        Some("2026-10-25T23:59:59.000-03:00")
+    );
+}
+
+#[tokio::test]
+async fn test_search_chargebacks() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/chargebacks/search"))
+        .and(query_param("payment_id", "123456"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "paging": { "total": 1, "offset": 0, "limit": 30 },
+            "results": [chargeback_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let search = client.search_chargebacks(123456).await.unwrap();
+    assert_eq!(search.results.len(), 1);
+    assert_eq!(search.results[0].id, "206000000001");
+}
+
+#[tokio::test]
+async fn test_upload_chargeback_documentation() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/chargebacks/206000000001/documentation"))
+        .and(header_regex(
+            "Content-Type",
+            "^multipart/form-data; boundary=mercadopago-sdk-[0-9a-f]+$",
+        ))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let files = [
+        DocumentationFile::new("invoice.pdf", "application/pdf", b"%PDF-1.4".to_vec()),
+        DocumentationFile::new("receipt.png", "image/png", vec![0x89, b'P', b'N', b'G']),
+    ];
+    client
+        .upload_chargeback_documentation("206000000001", &files)
+        .await
+        .unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body);
+    assert!(body.contains(
+        "Content-Disposition: form-data; name=\"files[]\"; filename=\"invoice.pdf\"\r\nContent-Type: application/pdf\r\n\r\n%PDF-1.4\r\n"
+    ));
+    assert!(body.contains("filename=\"receipt.png\""));
+    assert!(body.trim_end().ends_with("--"));
+}
+
+#[tokio::test]
+async fn test_upload_chargeback_documentation_requires_files() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let result = client
+        .upload_chargeback_documentation("206000000001", &[])
+        .await;
+    assert!(matches!(result, Err(Error::Internal(_))));
+}
@@ -1,6 +1,6 @@
 use mercadopago_sdk::MercadoPagoClient;
 use serde_json::json;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[tokio::test]
@@ -102,8 +102,8 @@ async fn test_client_retry_exhaustion() {
 
     let res = client.get("/retry-exhaust").send().await;
     match res {
-        Err(mercadopago_sdk::error::Error::ApiError { status, .. }) => {
-            assert_eq!(status, 429);
+        Err(mercadopago_sdk::error::Error::ApiError(api)) => {
+            assert_eq!(api.status, 429);
         }
         _ => panic!("Expected ApiError with status 429 after retry exhaustion"),
     }
@@ -139,6 +139,54 @@ async fn test_create_payment() {
     assert_eq!(payment.status, "approved");
 }
 
+#[tokio::test]
+async fn test_create_marketplace_payment() {
+    let mock_server = MockServer::start().await;
+    let seller = MercadoPagoClient::builder("SELLER_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    let payment_request = mercadopago_sdk::models::payments::PaymentRequest {
+        transaction_amount: 100.0,
+        payment_method_id: "visa".to_string(),
+        ..Default::default()
+    };
+
+    Mock::given(method("POST"))
+        .and(path("/v1/payments"))
+        .and(header("Authorization", "Bearer SELLER_TOKEN"))
+        .and(body_partial_json(json!({ "application_fee": 10.0 })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 1,
+            "status": "approved",
+            "transaction_amount": 100.0
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let payment = seller
+        .create_marketplace_payment(payment_request.clone(), 10.0)
+        .await
+        .unwrap();
+    assert_eq!(payment.id, 1);
+
+    // Fees outside [0, amount) are rejected before hitting the API.
+    assert!(matches!(
+        seller
+            .create_marketplace_payment(payment_request.clone(), 100.0)
+            .await,
+        Err(mercadopago_sdk::error::Error::Internal(_))
+    ));
+    assert!(
+        seller
+            .create_marketplace_payment(payment_request, -1.0)
+            .await
+            .is_err()
+    );
+}
+
 #[tokio::test]
 async fn test_get_payment() {
     let mock_server = MockServer::start().await;
@@ -183,8 +231,8 @@ async fn test_api_error_handling() {
 
     let res = client.get_payment(999).await;
     match res {
-        Err(mercadopago_sdk::error::Error::ApiError { status, .. }) => {
-            assert_eq!(status, 404);
+        Err(mercadopago_sdk::error::Error::ApiError(api)) => {
+            assert_eq!(api.status, 404);
         }
         _ => panic!("Expected ApiError"),
     }
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::credentials::{
+    CredentialsProvider, EnvCredentials, ExposeSecret, SecretString,
+};
+use mercadopago_sdk::error::Error;
+use std::sync::atomic::{AtomicU32, Ordering};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Hands out `TOKEN-<n>`, where `n` grows on every refresh.
+struct RotatingCredentials {
+    generation: AtomicU32,
+}
+
+#[async_trait]
+impl CredentialsProvider for RotatingCredentials {
+    async fn token(&self) -> Result<SecretString, Error> {
+        Ok(format!("TOKEN-{}", self.generation.load(Ordering::SeqCst)).into())
+    }
+
+    async fn refresh(&self, _rejected: &SecretString) -> Result<bool, Error> {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        Ok(true)
+    }
+}
+
+#[tokio::test]
+async fn test_provider_token_is_used_per_request() {
+    let mock_server = MockServer::start().await;
+    let provider = RotatingCredentials {
+        generation: AtomicU32::new(7),
+    };
+    let client = MercadoPagoClient::builder("IGNORED")
+        .base_url(&mock_server.uri())
+        .credentials(provider)
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer TOKEN-7"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.get("/test").send().await.unwrap();
+    assert_eq!(res.status(), 200);
+}
+
+#[tokio::test]
+async fn test_unauthorized_triggers_single_refresh_and_retry() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("IGNORED")
+        .base_url(&mock_server.uri())
+        .credentials(RotatingCredentials {
+            generation: AtomicU32::new(0),
+        })
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer TOKEN-0"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer TOKEN-1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client.get("/test").send().await.unwrap();
+    assert_eq!(res.status(), 200);
+
+    // A second 401 in a row is returned to the caller.
+    Mock::given(method("GET"))
+        .and(path("/always-401"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+            "message": "invalid access token",
+            "error": "unauthorized",
+            "status": 401
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    match client.get("/always-401").send().await {
+        Err(Error::ApiError(api)) => assert_eq!(api.status, 401),
+        other => panic!("Expected ApiError 401, got {:?}", other.map(|r| r.status())),
+    }
+}
+
+struct FailingRefresh;
+
+#[async_trait]
+impl CredentialsProvider for FailingRefresh {
+    async fn token(&self) -> Result<SecretString, Error> {
+        Ok("TOKEN".into())
+    }
+
+    async fn refresh(&self, _rejected: &SecretString) -> Result<bool, Error> {
+        Err(Error::Internal("token source unavailable".to_string()))
+    }
+}
+
+#[tokio::test]
+async fn test_failed_refresh_returns_original_unauthorized() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("IGNORED")
+        .base_url(&mock_server.uri())
+        .credentials(FailingRefresh)
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .respond_with(ResponseTemplate::new(401).insert_header("x-request-id", "req-401"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let err = client.get("/test").send().await.unwrap_err();
+    assert_eq!(err.status(), Some(401));
+    assert_eq!(err.request_id(), Some("req-401"));
+}
+
+#[tokio::test]
+async fn test_static_token_is_not_retried_on_unauthorized() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("STATIC")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    assert!(client.get("/test").send().await.is_err());
+}
+
+#[tokio::test]
+async fn test_env_credentials() {
+    let var = "MP_SDK_TEST_ENV_CREDENTIALS";
+    // SAFETY: this variable is only used by this test.
+    unsafe { std::env::set_var(var, "ENV-1") };
+
+    let provider = EnvCredentials::new(var);
+    let first = provider.token().await.unwrap();
+    assert_eq!(first.expose_secret(), "ENV-1");
+    assert!(!provider.refresh(&first).await.unwrap());
+
+    unsafe { std::env::set_var(var, "ENV-2") };
+    assert_eq!(provider.token().await.unwrap().expose_secret(), "ENV-1");
+    assert!(provider.refresh(&first).await.unwrap());
+    assert_eq!(provider.token().await.unwrap().expose_secret(), "ENV-2");
+
+    unsafe { std::env::remove_var(var) };
+    assert!(EnvCredentials::new(var).token().await.is_err());
+}
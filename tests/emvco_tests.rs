@@ -0,0 +1,91 @@
+use mercadopago_sdk::emvco::{EmvcoError, EmvcoPayload, crc16};
+use mercadopago_sdk::models::instore::QrOrderResponse;
+
+const MP_QR: &str = "00020101021243650016COM.MERCADOLIBRE02013063638f1192a-5fd1-4180-a180-8bcae3556bc35204000053039865802BR5919IZABEL AAAA DE MELO6007BARUERI62070503***6304BC66";
+
+#[test]
+fn test_crc16_ccitt_false() {
+    // Standard check value for CRC-16/CCITT-FALSE.
+    assert_eq!(crc16("123456789"), 0x29B1);
+}
+
+#[test]
+fn test_parse_mercadopago_payload() {
+    let payload = EmvcoPayload::parse(MP_QR).unwrap();
+
+    assert_eq!(payload.payload_format_indicator, "01");
+    assert!(payload.is_dynamic());
+    assert_eq!(payload.merchant_category_code, "0000");
+    assert_eq!(payload.transaction_currency, "986");
+    assert_eq!(payload.transaction_amount, None);
+    assert_eq!(payload.country_code, "BR");
+    assert_eq!(payload.merchant_name, "IZABEL AAAA DE MELO");
+    assert_eq!(payload.merchant_city, "BARUERI");
+    assert_eq!(payload.crc, 0xBC66);
+
+    let account = &payload.merchant_account_info[0];
+    assert_eq!(account.tag, 43);
+    assert_eq!(
+        account.globally_unique_identifier().as_deref(),
+        Some("COM.MERCADOLIBRE")
+    );
+    assert_eq!(account.subfields().unwrap().len(), 3);
+
+    let additional = payload.additional_data_fields().unwrap();
+    assert_eq!(additional[0].tag, 5);
+    assert_eq!(additional[0].value, "***");
+}
+
+#[test]
+fn test_encode_roundtrip_and_amount_change() {
+    let mut payload = EmvcoPayload::parse(MP_QR).unwrap();
+    assert_eq!(payload.encode(), MP_QR);
+
+    payload.transaction_amount = Some("150.00".to_string());
+    let encoded = payload.encode();
+    let reparsed = EmvcoPayload::parse(&encoded).unwrap();
+    assert!(reparsed.matches_amount(150.0));
+    assert!(!reparsed.matches_amount(149.0));
+    assert!(encoded.contains("5406150.00"));
+}
+
+#[test]
+fn test_crc_mismatch_is_rejected() {
+    let tampered = MP_QR.replace("IZABEL", "ISABEL");
+    match EmvcoPayload::parse(&tampered) {
+        Err(EmvcoError::CrcMismatch { expected, .. }) => assert_eq!(expected, 0xBC66),
+        other => panic!("Expected CrcMismatch, got {:?}", other),
+    }
+    assert!(EmvcoPayload::parse_unverified(&tampered).is_ok());
+}
+
+#[test]
+fn test_malformed_payloads() {
+    assert_eq!(EmvcoPayload::parse("000201"), Err(EmvcoError::InvalidCrc));
+    assert!(matches!(
+        EmvcoPayload::parse("0002"),
+        Err(EmvcoError::Truncated(_))
+    ));
+    assert!(matches!(
+        EmvcoPayload::parse("AB0201"),
+        Err(EmvcoError::InvalidHeader(0))
+    ));
+
+    // Valid CRC but no merchant name (tag 59).
+    let minimal = "00020126200016COM.MERCADOLIBRE5204000053030325802AR6012Buenos Aires6304";
+    let data = format!("{}{:04X}", minimal, crc16(minimal));
+    assert_eq!(
+        EmvcoPayload::parse(&data),
+        Err(EmvcoError::MissingField(59))
+    );
+}
+
+#[test]
+fn test_qr_order_response_emvco_payload() {
+    let response = QrOrderResponse {
+        qr_data: MP_QR.to_string(),
+        in_store_order_id: "order-abc".to_string(),
+    };
+    let payload = response.emvco_payload().unwrap();
+    assert_eq!(payload.country_code, "BR");
+}
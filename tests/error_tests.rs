@@ -1,43 +1,186 @@
-use mercadopago_sdk::error::Error;
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::error::{ApiError, Error, ErrorKind};
 use serde_json::json;
+use std::error::Error as _;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
 
-#[test]
-fn test_parse_api_error() {
-    let error_json = json!({
-        "message": "Invalid access token",
-        "error": "invalid_token",
-        "status": 401,
-        "cause": [
-            {
-                "code": "1",
-                "description": "Invalid parameter",
-                "data": "access_token"
-            }
-        ]
-    });
-
-    let error_str = error_json.to_string();
-    let api_error: Error = serde_json::from_str(&error_str).expect("Failed to parse error");
-
-    match api_error {
-        Error::ApiError {
-            message, status, ..
-        } => {
-            assert_eq!(message, "Invalid access token");
-            assert_eq!(status, 401);
+async fn error_for(response: ResponseTemplate) -> Error {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .max_retries(0)
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/error"))
+        .respond_with(response)
+        .mount(&mock_server)
+        .await;
+
+    client.get("/v1/error").send().await.unwrap_err()
+}
+
+#[tokio::test]
+async fn test_parse_api_error() {
+    let err = error_for(
+        ResponseTemplate::new(400)
+            .insert_header("x-request-id", "req-123")
+            .set_body_json(json!({
+                "message": "Invalid parameter",
+                "error": "bad_request",
+                "status": 400,
+                "cause": [
+                    {
+                        "code": 4020,
+                        "description": "notification_url attribute must be url valid",
+                        "data": "notification_url"
+                    }
+                ]
+            })),
+    )
+    .await;
+
+    match &err {
+        Error::ApiError(api) => {
+            assert_eq!(api.message, "Invalid parameter");
+            assert_eq!(api.error, "bad_request");
+            assert_eq!(api.status, 400);
+            assert_eq!(api.request_id.as_deref(), Some("req-123"));
+            assert!(api.body.contains("notification_url"));
         }
         _ => panic!("Expected ApiError"),
     }
+
+    match err.kind() {
+        ErrorKind::Validation { causes } => {
+            assert_eq!(causes.len(), 1);
+            assert_eq!(causes[0].code, Some(json!(4020)));
+        }
+        other => panic!("Expected Validation, got {:?}", other),
+    }
+    assert_eq!(err.request_id(), Some("req-123"));
+    assert!(err.is_client_error());
+    assert!(!err.is_retryable());
+}
+
+#[tokio::test]
+async fn test_error_kinds_by_status() {
+    for (status, kind) in [
+        (401, ErrorKind::Unauthorized),
+        (403, ErrorKind::Forbidden),
+        (404, ErrorKind::NotFound),
+        (409, ErrorKind::Conflict),
+        (500, ErrorKind::Server),
+        (503, ErrorKind::Server),
+    ] {
+        let err = error_for(ResponseTemplate::new(status)).await;
+        assert_eq!(err.kind(), kind, "status {}", status);
+        assert_eq!(err.status(), Some(status));
+        assert_eq!(err.is_retryable(), status >= 500, "status {}", status);
+        assert_eq!(err.is_client_error(), status < 500, "status {}", status);
+    }
+}
+
+#[tokio::test]
+async fn test_rate_limited_error_keeps_retry_after() {
+    let err = error_for(ResponseTemplate::new(429).insert_header("Retry-After", "7")).await;
+
+    assert_eq!(
+        err.kind(),
+        ErrorKind::RateLimited {
+            retry_after: Some(Duration::from_secs(7))
+        }
+    );
+    assert!(err.is_retryable());
+    assert!(err.is_client_error());
+}
+
+#[tokio::test]
+async fn test_non_json_error_body_is_kept() {
+    let err =
+        error_for(ResponseTemplate::new(502).set_body_string("<html>Bad Gateway</html>")).await;
+
+    match &err {
+        Error::ApiError(api) => {
+            assert_eq!(api.status, 502);
+            assert_eq!(api.body, "<html>Bad Gateway</html>");
+            assert_eq!(api.message, "<html>Bad Gateway</html>");
+        }
+        _ => panic!("Expected ApiError, got {:?}", err),
+    }
+    assert_eq!(err.kind(), ErrorKind::Server);
+}
+
+#[tokio::test]
+async fn test_single_cause_object_is_accepted() {
+    let err = error_for(ResponseTemplate::new(422).set_body_json(json!({
+        "message": "Unprocessable",
+        "cause": { "code": "invalid_amount", "description": "amount must be positive" }
+    })))
+    .await;
+
+    match err.kind() {
+        ErrorKind::Validation { causes } => {
+            assert_eq!(
+                causes[0].description.as_deref(),
+                Some("amount must be positive")
+            );
+        }
+        other => panic!("Expected Validation, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_network_error_keeps_source() {
+    // Nothing listens on port 9 (discard) in the test environment.
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url("http://127.0.0.1:9")
+        .build()
+        .unwrap();
+
+    let err = client.get("/v1/payments/1").send().await.unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::Network);
+    assert!(err.is_retryable());
+    let source = err.source().expect("Network errors should expose a source");
+    assert!(source.downcast_ref::<reqwest::Error>().is_some());
+}
+
+#[tokio::test]
+async fn test_timeout_error() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/slow"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+        .mount(&mock_server)
+        .await;
+
+    let err = client.get("/slow").send().await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Timeout);
+    assert!(err.is_retryable());
+    assert!(err.source().is_some());
 }
 
 #[test]
 fn test_api_error_display_includes_status() {
-    let error = Error::ApiError {
+    let error = Error::ApiError(Box::new(ApiError {
+        status: 404,
         message: "Not found".to_string(),
         error: "not_found".to_string(),
-        status: 404,
-        cause: None,
-    };
+        cause: Vec::new(),
+        request_id: Some("abc-1".to_string()),
+        retry_after: None,
+        body: String::new(),
+    }));
 
     let display = format!("{}", error);
     assert!(
@@ -50,6 +193,11 @@ fn test_api_error_display_includes_status() {
         "Display should contain message: {}",
         display
     );
+    assert!(
+        display.contains("abc-1"),
+        "Display should contain request id: {}",
+        display
+    );
 }
 
 #[test]
@@ -57,6 +205,7 @@ fn test_internal_error_variant() {
     let error = Error::Internal("something went wrong".to_string());
     let display = format!("{}", error);
     assert!(display.contains("something went wrong"));
+    assert_eq!(error.kind(), ErrorKind::Internal);
 }
 
 #[test]
@@ -66,9 +215,11 @@ fn test_serde_json_error_conversion() {
     let error: Error = Error::from(serde_err);
 
     match error {
-        Error::Serialization(msg) => {
-            assert!(!msg.is_empty());
+        Error::Serialization(ref source) => {
+            assert!(!source.to_string().is_empty());
+            assert!(source.downcast_ref::<serde_json::Error>().is_some());
         }
         _ => panic!("Expected Serialization error"),
     }
+    assert!(std::error::Error::source(&error).is_some());
 }
@@ -0,0 +1,221 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::client::breaker::{CircuitBreakerConfig, EndpointGroup};
+use mercadopago_sdk::client::ratelimit::{RateLimit, RateLimiterConfig};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn payment(id: u64) -> serde_json::Value {
+    json!({ "id": id, "status": "approved", "transaction_amount": 10.0 })
+}
+
+#[tokio::test]
+async fn test_stats_count_requests_by_route() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    for id in [1, 2] {
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/payments/{}", id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(payment(id)))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/3"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({ "message": "not found" })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/4"))
+        .respond_with(ResponseTemplate::new(304))
+        .mount(&mock_server)
+        .await;
+
+    client.get_payment(1).await.unwrap();
+    client.clone().get_payment(2).await.unwrap();
+    client.get_payment(3).await.unwrap_err();
+    client.get_me().await.unwrap_err();
+    client.get_payment(4).await.unwrap_err();
+
+    let stats = client.stats();
+    assert_eq!(stats.requests, 5);
+    assert_eq!(stats.successes, 2);
+    assert_eq!(stats.redirects, 1);
+    assert_eq!(stats.client_errors, 1);
+    assert_eq!(stats.server_errors, 1);
+    assert_eq!(stats.transport_errors, 0);
+    assert_eq!(stats.retries, 0);
+
+    let payments = &stats.routes["GET /v1/payments/{id}"];
+    assert_eq!(payments.requests, 4);
+    assert_eq!(payments.errors, 2);
+    assert!(payments.max_latency >= payments.mean_latency());
+    assert_eq!(stats.routes["GET /users/me"].errors, 1);
+}
+
+#[tokio::test]
+async fn test_stats_routes_use_endpoint_templates() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(wiremock::matchers::any())
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+
+    // Ids without digits are still ids.
+    for pos in ["CAJA", "caja-principal"] {
+        client
+            .post(&format!(
+                "/instore/orders/qr/seller/collectors/123/pos/{}/qrs",
+                pos
+            ))
+            .send()
+            .await
+            .unwrap();
+    }
+    client
+        .get("/point/integration-api/devices/PAX_A910__SMARTPOS")
+        .send()
+        .await
+        .unwrap();
+    client
+        .get("/v1/payments/search?status=approved")
+        .send()
+        .await
+        .unwrap();
+    // Paths the SDK does not know share one route.
+    for path in ["/v1/customers/abc", "/v1/customers/def/cards"] {
+        client.get(path).send().await.unwrap();
+    }
+
+    let routes = client.stats().routes;
+    assert_eq!(
+        routes["POST /instore/orders/qr/seller/collectors/{id}/pos/{id}/qrs"].requests,
+        2
+    );
+    assert_eq!(
+        routes["GET /point/integration-api/devices/{id}"].requests,
+        1
+    );
+    assert_eq!(routes["GET /v1/payments/search"].requests, 1);
+    assert_eq!(routes["GET {other}"].requests, 2);
+    assert_eq!(routes.len(), 4);
+}
+
+#[tokio::test]
+async fn test_stats_count_retries_and_rejections() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .circuit_breaker(
+            CircuitBreakerConfig::new()
+                .failure_threshold(1)
+                .cooldown(Duration::from_secs(60)),
+        )
+        .rate_limiter(RateLimiterConfig::new().group(
+            EndpointGroup::Payments,
+            RateLimit::per_second(20.0).burst(1),
+        ))
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment(1)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&mock_server)
+        .await;
+
+    // The retry after the 429 waits for a second token.
+    client.get_payment(1).await.unwrap();
+    // One failure opens the circuit for the group; the next call is rejected.
+    client.get_me().await.unwrap_err();
+    client.get_me().await.unwrap_err();
+
+    let stats = client.stats();
+    assert_eq!(stats.requests, 2);
+    assert_eq!(stats.retries, 1);
+    assert_eq!(stats.rate_limit_delays, 1);
+    assert_eq!(stats.circuit_rejections, 1);
+    assert_eq!(stats.routes["GET /v1/payments/{id}"].requests, 1);
+    assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+}
+
+#[cfg(feature = "metrics")]
+#[tokio::test]
+async fn test_metrics_are_exported() {
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment(42)))
+        .mount(&mock_server)
+        .await;
+
+    // The local recorder is per-thread; the default test runtime is single-threaded.
+    let _guard = metrics::set_default_local_recorder(&recorder);
+    client.get_payment(42).await.unwrap();
+
+    let metrics: Vec<_> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let key = key.key();
+            let labels: Vec<String> = key
+                .labels()
+                .map(|l| format!("{}={}", l.key(), l.value()))
+                .collect();
+            (key.name().to_string(), labels, value)
+        })
+        .collect();
+
+    let (_, labels, value) = metrics
+        .iter()
+        .find(|(name, _, _)| name == "mercadopago_requests_total")
+        .expect("requests counter");
+    assert_eq!(
+        labels,
+        &["method=GET", "route=/v1/payments/{id}", "status_class=2xx"]
+    );
+    assert_eq!(value, &DebugValue::Counter(1));
+
+    let (_, _, value) = metrics
+        .iter()
+        .find(|(name, _, _)| name == "mercadopago_request_duration_seconds")
+        .expect("latency histogram");
+    assert!(matches!(value, DebugValue::Histogram(samples) if samples.len() == 1));
+}
@@ -13,6 +13,7 @@ fn test_payment_request_serialization_full() {
                 id_type: "DNI".to_string(),
                 number: "12345678".to_string(),
             }),
+            ..Default::default()
         },
         installments: Some(3),
         external_reference: Some("REF123".to_string()),
@@ -238,3 +239,243 @@ fn test_skip_serializing_none_fields() {
     assert!(!serialized.contains("external_reference"));
     assert!(!serialized.contains("notification_url"));
 }
+
+#[test]
+fn test_payment_status_parsing() {
+    use mercadopago_sdk::models::payments::PaymentStatus;
+
+    assert_eq!(PaymentStatus::parse("approved"), PaymentStatus::Approved);
+    assert_eq!(
+        PaymentStatus::parse("charged_back"),
+        PaymentStatus::ChargedBack
+    );
+    assert_eq!(
+        PaymentStatus::parse("something_new"),
+        PaymentStatus::Other("something_new".to_string())
+    );
+    assert_eq!(PaymentStatus::InProcess.as_str(), "in_process");
+    assert!(PaymentStatus::Rejected.is_final());
+    assert!(!PaymentStatus::Pending.is_final());
+
+    let response: PaymentResponse = serde_json::from_value(json!({
+        "id": 1,
+        "status": "in_mediation",
+        "transaction_amount": 10.0
+    }))
+    .unwrap();
+    assert_eq!(response.payment_status(), PaymentStatus::InMediation);
+}
+
+#[test]
+fn test_payment_request_marketplace_fields() {
+    use mercadopago_sdk::models::payments::{
+        AdditionalInfo, AdditionalInfoItem, ReceiverAddress, Shipments, ThreeDSecureMode,
+    };
+
+    let request = PaymentRequest {
+        transaction_amount: 1000.0,
+        payment_method_id: "visa".to_string(),
+        sponsor_id: Some(42),
+        statement_descriptor: Some("MITIENDA".to_string()),
+        binary_mode: Some(true),
+        metadata: Some(json!({ "order_id": "A-1" })),
+        three_d_secure_mode: Some(ThreeDSecureMode::Optional),
+        additional_info: Some(AdditionalInfo {
+            items: Some(vec![AdditionalInfoItem {
+                title: "Remera".to_string(),
+                quantity: 2,
+                unit_price: 500.0,
+                ..Default::default()
+            }]),
+            shipments: Some(Shipments {
+                receiver_address: Some(ReceiverAddress {
+                    zip_code: Some("1414".to_string()),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+    .with_application_fee_percentage(7.5);
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized["application_fee"], 75.0);
+    assert_eq!(serialized["sponsor_id"], 42);
+    assert_eq!(serialized["statement_descriptor"], "MITIENDA");
+    assert_eq!(serialized["binary_mode"], true);
+    assert_eq!(serialized["metadata"]["order_id"], "A-1");
+    assert_eq!(serialized["three_d_secure_mode"], "optional");
+    assert_eq!(serialized["additional_info"]["items"][0]["quantity"], 2);
+    assert_eq!(
+        serialized["additional_info"]["shipments"]["receiver_address"]["zip_code"],
+        "1414"
+    );
+    assert!(serialized["additional_info"].get("payer").is_none());
+}
+
+#[test]
+fn test_payment_response_fee_amount() {
+    let response: PaymentResponse = serde_json::from_value(json!({
+        "id": 1,
+        "status": "approved",
+        "transaction_amount": 1000.0,
+        "fee_details": [
+            { "type": "mercadopago_fee", "amount": 49.9, "fee_payer": "collector" },
+            { "type": "application_fee", "amount": 75.0, "fee_payer": "collector" }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(response.fee_amount("application_fee"), 75.0);
+    assert_eq!(response.fee_amount("mercadopago_fee"), 49.9);
+    assert_eq!(response.fee_amount("coupon_fee"), 0.0);
+}
+
+#[test]
+fn test_payment_response_three_ds_challenge() {
+    let response: PaymentResponse = serde_json::from_value(json!({
+        "id": 1,
+        "status": "pending",
+        "status_detail": "pending_challenge",
+        "transaction_amount": 100.0,
+        "three_ds_info": {
+            "external_resource_url": "https://acs.example.com/challenge?a=1&b=2",
+            "creq": "eyJ\"x\""
+        }
+    }))
+    .unwrap();
+
+    let challenge = response.challenge().expect("challenge");
+    let html = challenge.challenge_form_html();
+    assert!(html.contains(r#"action="https://acs.example.com/challenge?a=1&amp;b=2""#));
+    assert!(html.contains(r#"value="eyJ&quot;x&quot;""#));
+    assert!(html.contains("document.forms[0].submit()"));
+
+    let approved = PaymentResponse {
+        status: "approved".to_string(),
+        status_detail: Some("accredited".to_string()),
+        ..response
+    };
+    assert!(approved.challenge().is_none());
+}
+
+#[test]
+fn test_pix_payment_request_and_response() {
+    use mercadopago_sdk::models::payments::Payer;
+
+    let request = PaymentRequest::pix(
+        120.0,
+        Payer {
+            email: "comprador@exemplo.com.br".to_string(),
+            ..Default::default()
+        },
+    )
+    .with_date_of_expiration("2026-10-20T23:59:59.000-03:00");
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized["payment_method_id"], "pix");
+    assert_eq!(
+        serialized["date_of_expiration"],
+        "2026-10-20T23:59:59.000-03:00"
+    );
+    assert!(serialized["payer"].get("address").is_none());
+
+    let response: PaymentResponse = serde_json::from_value(json!({
+        "id": 1,
+        "status": "pending",
+        "status_detail": "pending_waiting_transfer",
+        "transaction_amount": 120.0,
+        "point_of_interaction": {
+            "type": "PIX",
+            "transaction_data": {
+                "qr_code": "00020126580014br.gov.bcb.pix",
+                "qr_code_base64": "iVBORw0KGgo=",
+                "ticket_url": "https://www.mercadopago.com.br/payments/1/ticket"
+            }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(response.pix_qr_code(), Some("00020126580014br.gov.bcb.pix"));
+    assert_eq!(response.pix_qr_code_base64(), Some("iVBORw0KGgo="));
+    assert_eq!(
+        response.ticket_url(),
+        Some("https://www.mercadopago.com.br/payments/1/ticket")
+    );
+}
+
+#[test]
+fn test_ticket_payment_request_requires_boleto_address() {
+    use mercadopago_sdk::models::payments::{Address, Identification, Payer};
+
+    let payer = Payer {
+        email: "comprador@exemplo.com.br".to_string(),
+        first_name: Some("Ana".to_string()),
+        last_name: Some("Silva".to_string()),
+        identification: Some(Identification {
+            id_type: "CPF".to_string(),
+            number: "19119119100".to_string(),
+        }),
+        address: Some(Address {
+            zip_code: Some("06233200".to_string()),
+            street_name: Some("Av. das Nações Unidas".to_string()),
+            street_number: Some("3003".to_string()),
+            neighborhood: Some("Bonfim".to_string()),
+            city: Some("Osasco".to_string()),
+            federal_unit: Some("SP".to_string()),
+        }),
+    };
+
+    let request = PaymentRequest::ticket(200.0, "bolbradesco", payer.clone()).unwrap();
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized["payer"]["address"]["federal_unit"], "SP");
+
+    let incomplete = Payer {
+        address: None,
+        ..payer.clone()
+    };
+    match PaymentRequest::ticket(200.0, "bolbradesco", incomplete.clone()) {
+        Err(mercadopago_sdk::error::Error::Internal(msg)) => {
+            assert!(msg.contains("address.zip_code"));
+            assert!(!msg.contains("first_name"));
+        }
+        other => panic!("Expected Internal error, got {:?}", other),
+    }
+
+    // Argentine and Mexican tickets don't need an address.
+    assert!(PaymentRequest::ticket(200.0, "rapipago", incomplete).is_ok());
+
+    // Blank values count as missing.
+    let blank = Payer {
+        first_name: Some("  ".to_string()),
+        address: Some(Address {
+            city: Some(String::new()),
+            ..payer.address.clone().unwrap()
+        }),
+        ..payer
+    };
+    match PaymentRequest::ticket(200.0, "bolbradesco", blank) {
+        Err(mercadopago_sdk::error::Error::Internal(msg)) => {
+            assert!(msg.contains("first_name"));
+            assert!(msg.contains("address.city"));
+            assert!(!msg.contains("last_name"));
+        }
+        other => panic!("Expected Internal error, got {:?}", other),
+    }
+
+    let response: PaymentResponse = serde_json::from_value(json!({
+        "id": 2,
+        "status": "pending",
+        "transaction_amount": 200.0,
+        "transaction_details": {
+            "external_resource_url": "https://www.mercadopago.com.br/payments/2/ticket",
+            "payment_method_reference_id": "23793.38128"
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        response.ticket_url(),
+        Some("https://www.mercadopago.com.br/payments/2/ticket")
+    );
+}
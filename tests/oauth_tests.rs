@@ -0,0 +1,252 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::credentials::ExposeSecret;
+use mercadopago_sdk::oauth::{OAuthClient, OAuthSession, Pkce};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn oauth_client(base_url: &str) -> OAuthClient {
+    let platform = MercadoPagoClient::builder("PLATFORM_TOKEN")
+        .base_url(base_url)
+        .build()
+        .unwrap();
+    OAuthClient::new(platform, "APP_ID", "SECRET", "https://example.com/cb")
+}
+
+#[test]
+fn test_pkce_s256_challenge() {
+    // Test vector from RFC 7636, appendix B.
+    let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    assert_eq!(
+        pkce.challenge(),
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+
+    let random = Pkce::new();
+    assert_eq!(random.verifier().len(), 43);
+    assert_ne!(random.verifier(), Pkce::new().verifier());
+}
+
+#[test]
+fn test_authorization_url() {
+    let oauth = oauth_client("http://localhost");
+    let pkce = Pkce::from_verifier("verifier");
+
+    let url = oauth.authorization_url("state-1", Some(&pkce));
+    assert!(url.starts_with("https://auth.mercadopago.com/authorization?"));
+    assert!(url.contains("client_id=APP_ID"));
+    assert!(url.contains("response_type=code"));
+    assert!(url.contains("state=state-1"));
+    assert!(url.contains("redirect_uri=https%3A%2F%2Fexample.com%2Fcb"));
+    assert!(url.contains(&format!("code_challenge={}", pkce.challenge())));
+    assert!(url.contains("code_challenge_method=S256"));
+
+    let plain = oauth
+        .auth_base_url("https://auth.mercadopago.com.br/")
+        .authorization_url("s", None);
+    assert!(plain.starts_with("https://auth.mercadopago.com.br/authorization?"));
+    assert!(!plain.contains("code_challenge"));
+}
+
+#[tokio::test]
+async fn test_exchange_code() {
+    let mock_server = MockServer::start().await;
+    let oauth = oauth_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_partial_json(json!({
+            "client_id": "APP_ID",
+            "client_secret": "SECRET",
+            "grant_type": "authorization_code",
+            "code": "TG-123",
+            "redirect_uri": "https://example.com/cb",
+            "code_verifier": "verifier"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "APP_USR-seller",
+            "token_type": "Bearer",
+            "expires_in": 15552000,
+            "scope": "offline_access read write",
+            "user_id": 987,
+            "refresh_token": "TG-refresh",
+            "public_key": "APP_USR-pk",
+            "live_mode": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let token = oauth
+        .exchange_code("TG-123", Some("verifier"))
+        .await
+        .unwrap();
+    assert_eq!(token.access_token.expose_secret(), "APP_USR-seller");
+    assert_eq!(token.user_id, 987);
+    assert_eq!(
+        token
+            .refresh_token
+            .as_ref()
+            .map(ExposeSecret::expose_secret),
+        Some("TG-refresh")
+    );
+    assert_eq!(token.scope.as_deref(), Some("offline_access read write"));
+}
+
+#[tokio::test]
+async fn test_client_refreshes_expiring_oauth_token() {
+    let mock_server = MockServer::start().await;
+    let oauth = oauth_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_partial_json(json!({
+            "grant_type": "refresh_token",
+            "refresh_token": "TG-old-refresh"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "NEW_TOKEN",
+            "expires_in": 15552000,
+            "user_id": 987,
+            "refresh_token": "TG-new-refresh"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .and(header("Authorization", "Bearer NEW_TOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "status": "approved",
+            "transaction_amount": 10.0
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let stored = serde_json::from_value(json!({
+        "access_token": "OLD_TOKEN",
+        "expires_in": 15552000,
+        "user_id": 987,
+        "refresh_token": "TG-old-refresh"
+    }))
+    .unwrap();
+
+    let persisted = Arc::new(Mutex::new(None));
+    let sink = persisted.clone();
+    let session = OAuthSession::new(oauth, stored)
+        .expires_at(SystemTime::now())
+        .await
+        .on_refresh(move |token| {
+            *sink.lock().unwrap() = token
+                .refresh_token
+                .as_ref()
+                .map(|t| t.expose_secret().to_string())
+        });
+
+    let seller = MercadoPagoClient::builder("")
+        .base_url(&mock_server.uri())
+        .oauth_session(session.clone())
+        .build()
+        .unwrap();
+
+    // The first call refreshes, the second one reuses the new token.
+    seller.get_payment(1).await.unwrap();
+    seller.get_payment(1).await.unwrap();
+
+    assert_eq!(
+        session.token().await.access_token.expose_secret(),
+        "NEW_TOKEN"
+    );
+    assert_eq!(persisted.lock().unwrap().as_deref(), Some("TG-new-refresh"));
+}
+
+#[tokio::test]
+async fn test_concurrent_unauthorized_requests_refresh_once() {
+    let mock_server = MockServer::start().await;
+    let oauth = oauth_client(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "NEW_TOKEN",
+            "expires_in": 15552000,
+            "user_id": 987,
+            "refresh_token": "TG-new-refresh"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .and(header("Authorization", "Bearer REVOKED_TOKEN"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "message": "invalid access token",
+            "status": 401
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .and(header("Authorization", "Bearer NEW_TOKEN"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "status": "approved",
+            "transaction_amount": 10.0
+        })))
+        .expect(4)
+        .mount(&mock_server)
+        .await;
+
+    // Not expired, but revoked on the server side.
+    let stored = serde_json::from_value(json!({
+        "access_token": "REVOKED_TOKEN",
+        "expires_in": 15552000,
+        "user_id": 987,
+        "refresh_token": "TG-old-refresh"
+    }))
+    .unwrap();
+    let seller = MercadoPagoClient::builder("")
+        .base_url(&mock_server.uri())
+        .oauth_session(OAuthSession::new(oauth, stored))
+        .build()
+        .unwrap();
+
+    let (a, b, c, d) = tokio::join!(
+        seller.get_payment(1),
+        seller.get_payment(1),
+        seller.get_payment(1),
+        seller.get_payment(1)
+    );
+    for res in [a, b, c, d] {
+        assert_eq!(res.unwrap().id, 1);
+    }
+}
+
+#[test]
+fn test_token_round_trips_through_storage() {
+    use mercadopago_sdk::models::oauth::OAuthToken;
+
+    let stored = json!({
+        "access_token": "APP_USR-seller",
+        "refresh_token": "TG-refresh",
+        "expires_in": 15552000,
+        "user_id": 987
+    });
+    let token: OAuthToken = serde_json::from_value(stored.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&token).unwrap(), stored);
+
+    let token: OAuthToken = serde_json::from_value(json!({
+        "access_token": "APP_USR-seller",
+        "expires_in": 21600,
+        "user_id": 987
+    }))
+    .unwrap();
+    assert!(token.refresh_token.is_none());
+}
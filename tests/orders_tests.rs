@@ -0,0 +1,202 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::client::new_idempotency_key;
+use mercadopago_sdk::error::Error;
+use mercadopago_sdk::models::orders::{
+    OrderRequest, OrderType, Payment, PaymentMethod, ProcessingMode, RefundOrderRequest,
+    RefundTransaction, Transactions,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup() -> (MockServer, MercadoPagoClient) {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+    (mock_server, client)
+}
+
+fn order_body(status: &str) -> serde_json::Value {
+    json!({
+        "id": "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3",
+        "type": "online",
+        "external_reference": "ORDER-1",
+        "total_amount": "200.00",
+        "processing_mode": "manual",
+        "status": status,
+        "transactions": {
+            "payments": [{
+                "id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3",
+                "amount": "200.00",
+                "status": status,
+                "reference_id": "12345678",
+                "payment_method": { "id": "master", "type": "credit_card", "installments": 1 }
+            }]
+        },
+        "created_date": "2026-10-18T12:00:00.000Z"
+    })
+}
+
+#[tokio::test]
+async fn test_create_and_process_manual_order() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/orders"))
+        .and(header("X-Idempotency-Key", "key-create"))
+        .and(body_partial_json(json!({
+            "type": "online",
+            "external_reference": "ORDER-1",
+            "total_amount": "200.00",
+            "processing_mode": "manual",
+            "transactions": {
+                "payments": [{
+                    "amount": "200.00",
+                    "payment_method": { "id": "master", "type": "credit_card", "token": "card-token", "installments": 1 }
+                }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_body("created")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/orders/ORD01JQ4S4KY8HWQ6NA5PXB65B3D3/process"))
+        .and(header("X-Idempotency-Key", "key-process"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_body("processed")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let request = OrderRequest {
+        order_type: OrderType::Online,
+        external_reference: "ORDER-1".to_string(),
+        total_amount: "200.00".to_string(),
+        processing_mode: Some(ProcessingMode::Manual),
+        transactions: Some(Transactions {
+            payments: vec![Payment {
+                amount: "200.00".to_string(),
+                payment_method: Some(PaymentMethod {
+                    id: Some("master".to_string()),
+                    method_type: Some("credit_card".to_string()),
+                    token: Some("card-token".to_string()),
+                    installments: Some(1),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let order = client.create_order(request, "key-create").await.unwrap();
+    assert_eq!(order.status, "created");
+    assert_eq!(order.processing_mode, Some(ProcessingMode::Manual));
+
+    let order = client
+        .process_order(&order.id, "key-process")
+        .await
+        .unwrap();
+    assert_eq!(order.status, "processed");
+    assert_eq!(
+        order.transactions.payments[0].reference_id.as_deref(),
+        Some("12345678")
+    );
+}
+
+#[tokio::test]
+async fn test_get_cancel_and_refund_order() {
+    let (mock_server, client) = setup().await;
+    let id = "ORD01JQ4S4KY8HWQ6NA5PXB65B3D3";
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v1/orders/{}", id)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_body("processed")))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/v1/orders/{}/cancel", id)))
+        .and(header("X-Idempotency-Key", "key-cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(order_body("canceled")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/v1/orders/{}/refund", id)))
+        .and(header("X-Idempotency-Key", "key-refund"))
+        .and(body_json(json!({
+            "transactions": [{ "id": "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3", "amount": "50.00" }]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(order_body("processed")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    assert_eq!(client.get_order(id).await.unwrap().status, "processed");
+    assert_eq!(
+        client.cancel_order(id, "key-cancel").await.unwrap().status,
+        "canceled"
+    );
+
+    let partial = RefundOrderRequest {
+        transactions: vec![RefundTransaction {
+            id: "PAY01JQ4S4KY8HWQ6NA5PXB65B3D3".to_string(),
+            amount: "50.00".to_string(),
+        }],
+    };
+    client
+        .refund_order(id, Some(partial), "key-refund")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_search_orders() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/orders"))
+        .and(query_param("external_reference", "ORDER-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [order_body("processed")],
+            "paging": { "total": 1, "offset": 0, "limit": 50 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let search = client
+        .search_orders(&[("external_reference", "ORDER-1")])
+        .await
+        .unwrap();
+    assert_eq!(search.data.len(), 1);
+    assert_eq!(search.data[0].order_type, Some(OrderType::Online));
+}
+
+#[tokio::test]
+async fn test_order_requires_idempotency_key() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let result = client.create_order(OrderRequest::default(), " ").await;
+    assert!(matches!(result, Err(Error::Internal(_))));
+    assert!(matches!(
+        client.process_order("ORD1", "").await,
+        Err(Error::Internal(_))
+    ));
+
+    let key = new_idempotency_key().unwrap();
+    assert_eq!(key.len(), 36);
+    assert_eq!(&key[14..15], "4");
+    assert_ne!(key, new_idempotency_key().unwrap());
+}
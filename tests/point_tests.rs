@@ -0,0 +1,172 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::models::point::{
+    OperatingMode, PaymentIntentAdditionalInfo, PaymentIntentPayment, PaymentIntentRequest,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DEVICE: &str = "PAX_A910__SMARTPOS1234567890";
+
+async fn setup() -> (MockServer, MercadoPagoClient) {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+    (mock_server, client)
+}
+
+#[tokio::test]
+async fn test_list_point_devices() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/point/integration-api/devices"))
+        .and(query_param("store_id", "STORE1"))
+        .and(query_param("pos_id", "42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "devices": [{
+                "id": DEVICE,
+                "pos_id": 42,
+                "store_id": "STORE1",
+                "external_pos_id": "CAJA1",
+                "operating_mode": "STANDALONE"
+            }],
+            "paging": { "total": 1, "offset": 0, "limit": 50 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let list = client
+        .list_point_devices(Some("STORE1"), Some(42))
+        .await
+        .unwrap();
+    assert_eq!(list.devices[0].id, DEVICE);
+    assert_eq!(list.devices[0].operating_mode, OperatingMode::Standalone);
+}
+
+#[tokio::test]
+async fn test_change_device_operating_mode() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/point/integration-api/devices/{}", DEVICE)))
+        .and(body_json(json!({ "operating_mode": "PDV" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "operating_mode": "PDV" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let res = client
+        .change_device_operating_mode(DEVICE, OperatingMode::Pdv)
+        .await
+        .unwrap();
+    assert_eq!(res.operating_mode, OperatingMode::Pdv);
+}
+
+#[tokio::test]
+async fn test_payment_intent_lifecycle() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/point/integration-api/devices/{}/payment-intents",
+            DEVICE
+        )))
+        .and(body_json(json!({
+            "amount": 1500,
+            "description": "Café",
+            "payment": { "installments": 1, "type": "credit_card" },
+            "additional_info": { "external_reference": "ORDER-1", "print_on_terminal": true }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": "7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+            "device_id": DEVICE,
+            "amount": 1500,
+            "description": "Café"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/point/integration-api/payment-intents/7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+            "state": "FINISHED",
+            "amount": 1500,
+            "device_id": DEVICE,
+            "payment": { "id": 16499678033u64 }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/point/integration-api/devices/{}/payment-intents/7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1",
+            DEVICE
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "7f25f9aa-eea6-4f9c-bf16-a341f71ba2f1"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let intent = client
+        .create_payment_intent(
+            DEVICE,
+            PaymentIntentRequest {
+                amount: 1500,
+                description: Some("Café".to_string()),
+                payment: Some(PaymentIntentPayment {
+                    installments: Some(1),
+                    payment_type: Some("credit_card".to_string()),
+                    ..Default::default()
+                }),
+                additional_info: Some(PaymentIntentAdditionalInfo {
+                    external_reference: Some("ORDER-1".to_string()),
+                    print_on_terminal: Some(true),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(intent.amount, Some(1500));
+
+    let processed = client.get_payment_intent(&intent.id).await.unwrap();
+    assert_eq!(processed.state.as_deref(), Some("FINISHED"));
+    assert_eq!(processed.payment_id(), Some(16499678033));
+
+    client
+        .cancel_payment_intent(DEVICE, &intent.id)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_get_payment_intent_events() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/point/integration-api/payment-intents/events"))
+        .and(query_param("startDate", "2026-10-01"))
+        .and(query_param("endDate", "2026-10-02"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "events": [
+                { "payment_intent_id": "a", "status": "OPEN", "created_on": "2026-10-01" },
+                { "payment_intent_id": "a", "status": "FINISHED", "created_on": "2026-10-01" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let events = client
+        .get_payment_intent_events("2026-10-01", "2026-10-02")
+        .await
+        .unwrap();
+    assert_eq!(events.events.len(), 2);
+    assert_eq!(events.events[1].status, "FINISHED");
+}
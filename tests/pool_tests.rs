@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::client::pool::{ClientPool, TokenLoader};
+use mercadopago_sdk::credentials::{CredentialsProvider, StaticCredentials};
+use mercadopago_sdk::error::Error;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// In-memory token storage that counts loads.
+#[derive(Clone, Default)]
+struct MemoryTokens {
+    tokens: Arc<Mutex<HashMap<u64, String>>>,
+    loads: Arc<AtomicU32>,
+}
+
+#[async_trait]
+impl TokenLoader for MemoryTokens {
+    async fn load(&self, collector_id: u64) -> Result<Arc<dyn CredentialsProvider>, Error> {
+        self.loads.fetch_add(1, Ordering::SeqCst);
+        let token = self
+            .tokens
+            .lock()
+            .unwrap()
+            .get(&collector_id)
+            .cloned()
+            .ok_or_else(|| Error::Internal(format!("Unknown collector {}", collector_id)))?;
+        Ok(Arc::new(StaticCredentials::new(&token)))
+    }
+}
+
+fn base_client(uri: &str) -> MercadoPagoClient {
+    MercadoPagoClient::builder("PLATFORM")
+        .base_url(uri)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_pool_uses_per_collector_tokens() {
+    let mock_server = MockServer::start().await;
+    let storage = MemoryTokens::default();
+    storage.tokens.lock().unwrap().insert(1, "SELLER-1".into());
+    storage.tokens.lock().unwrap().insert(2, "SELLER-2".into());
+
+    for (user, token) in [(1, "SELLER-1"), (2, "SELLER-2")] {
+        Mock::given(method("GET"))
+            .and(path(format!("/users/{}/stores/search", user)))
+            .and(header("Authorization", format!("Bearer {}", token)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "paging": { "total": 0, "offset": 0, "limit": 30 },
+                "results": []
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let pool = ClientPool::new(base_client(&mock_server.uri()), storage.clone());
+    for _ in 0..2 {
+        pool.for_collector(1).search_stores(1).await.unwrap();
+        pool.for_collector(2).search_stores(2).await.unwrap();
+    }
+
+    assert_eq!(pool.len(), 2);
+    assert_eq!(storage.loads.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_pool_evicts_least_recently_used() {
+    let storage = MemoryTokens::default();
+    let pool = ClientPool::new(base_client("http://localhost"), storage).capacity(2);
+
+    pool.for_collector(1);
+    pool.for_collector(2);
+    pool.for_collector(1);
+    pool.for_collector(3);
+    assert_eq!(pool.len(), 2);
+
+    pool.invalidate(1);
+    assert_eq!(pool.len(), 1);
+    pool.invalidate(3);
+    assert!(pool.is_empty());
+}
+
+#[tokio::test]
+async fn test_pool_reloads_rotated_token_on_unauthorized() {
+    let mock_server = MockServer::start().await;
+    let storage = MemoryTokens::default();
+    storage.tokens.lock().unwrap().insert(7, "OLD".into());
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer OLD"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer NEW"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let pool = ClientPool::new(base_client(&mock_server.uri()), storage.clone());
+    let client = pool.for_collector(7);
+
+    // Warm the credentials, then rotate the token in storage.
+    assert!(client.get("/test").send().await.is_err());
+    storage.tokens.lock().unwrap().insert(7, "NEW".into());
+
+    let res = client.get("/test").send().await.unwrap();
+    assert_eq!(res.status(), 200);
+}
+
+#[tokio::test]
+async fn test_pool_insert_skips_loader() {
+    let mock_server = MockServer::start().await;
+    let storage = MemoryTokens::default();
+
+    Mock::given(method("GET"))
+        .and(path("/test"))
+        .and(header("Authorization", "Bearer KNOWN"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let pool = ClientPool::new(base_client(&mock_server.uri()), storage.clone());
+    pool.insert(9, Arc::new(StaticCredentials::new("KNOWN")));
+    pool.for_collector(9).get("/test").send().await.unwrap();
+
+    assert_eq!(storage.loads.load(Ordering::SeqCst), 0);
+}
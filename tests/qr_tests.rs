@@ -0,0 +1,70 @@
+#![cfg(feature = "qr-render")]
+
+use mercadopago_sdk::models::instore::QrOrderResponse;
+use mercadopago_sdk::qr::{ErrorCorrection, QrRenderer};
+
+fn response() -> QrOrderResponse {
+    QrOrderResponse {
+        qr_data: "00020101021243650016COM.MERCADOLIBRE02013063638f1192a-5fd1-4180-a180-8bcae3556bc35204000053039865802BR5919IZABEL AAAA DE MELO6007BARUERI62070503***6304BC66".to_string(),
+        in_store_order_id: "order-abc".to_string(),
+    }
+}
+
+#[test]
+fn test_qr_to_svg() {
+    let svg = response().to_svg().unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>"));
+    assert!(svg.contains("<path"));
+}
+
+#[test]
+fn test_qr_to_png_has_requested_size() {
+    let png = response().to_png(256).unwrap();
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    // Width and height are stored big-endian in the IHDR chunk.
+    let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+    assert_eq!((width, height), (256, 256));
+}
+
+#[test]
+fn test_qr_to_png_rejects_tiny_size() {
+    assert!(response().to_png(10).is_err());
+}
+
+#[test]
+fn test_qr_terminal_quiet_zone() {
+    let with_border = response().to_terminal_string().unwrap();
+    let without_border = response()
+        .qr_renderer()
+        .quiet_zone(0)
+        .to_terminal_string()
+        .unwrap();
+
+    let first_line = with_border.lines().next().unwrap();
+    assert!(first_line.trim().is_empty());
+    assert_eq!(
+        first_line.chars().count(),
+        without_border.lines().next().unwrap().chars().count() + 8
+    );
+}
+
+#[test]
+fn test_qr_logo_is_embedded() {
+    let logo = QrRenderer::new("logo")
+        .quiet_zone(0)
+        .error_correction(ErrorCorrection::Low)
+        .to_png(42)
+        .unwrap();
+
+    let response = response();
+    let renderer = response.qr_renderer().logo(logo, 0.2);
+    let svg = renderer.to_svg().unwrap();
+    assert!(svg.contains("data:image/png;base64,"));
+    assert!(renderer.to_png(300).is_ok());
+
+    let invalid = response.qr_renderer().logo(b"not a png".to_vec(), 0.2);
+    assert!(invalid.to_svg().is_err());
+}
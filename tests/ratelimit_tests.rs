@@ -0,0 +1,174 @@
+use mercadopago_sdk::client::breaker::EndpointGroup;
+use mercadopago_sdk::client::ratelimit::{RateLimit, RateLimiterConfig};
+use mercadopago_sdk::{ErrorKind, MercadoPagoClient};
+use serde_json::json;
+use std::time::{Duration, Instant, SystemTime};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn payment() -> serde_json::Value {
+    json!({ "id": 1, "status": "approved", "transaction_amount": 10.0 })
+}
+
+#[tokio::test]
+async fn test_rate_limiter_throttles_group() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .rate_limiter(RateLimiterConfig::new().group(
+            EndpointGroup::Payments,
+            RateLimit::per_second(10.0).burst(1),
+        ))
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 7 })))
+        .mount(&mock_server)
+        .await;
+
+    // Clones share the bucket: 4 requests at 10 req/s with a burst of 1.
+    let start = Instant::now();
+    tokio::join!(
+        async {
+            for _ in 0..2 {
+                client.get_payment(1).await.unwrap();
+            }
+        },
+        async {
+            let clone = client.clone();
+            for _ in 0..2 {
+                clone.get_payment(1).await.unwrap();
+            }
+        }
+    );
+    assert!(
+        start.elapsed() >= Duration::from_millis(280),
+        "requests were not throttled: {:?}",
+        start.elapsed()
+    );
+
+    // Groups without a limit are not delayed.
+    let start = Instant::now();
+    for _ in 0..5 {
+        client.get_me().await.unwrap();
+    }
+    assert!(start.elapsed() < Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_retry_after_is_capped_by_max_wait() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .max_retry_wait(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "30"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+        .mount(&mock_server)
+        .await;
+
+    let start = Instant::now();
+    client.get_payment(1).await.unwrap();
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(100), "{:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(5), "{:?}", elapsed);
+}
+
+#[tokio::test]
+async fn test_retry_after_http_date() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .max_retries(0)
+        .build()
+        .unwrap();
+
+    let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(120));
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", date.as_str()))
+        .mount(&mock_server)
+        .await;
+
+    let err = client.get_payment(1).await.unwrap_err();
+    match err.kind() {
+        ErrorKind::RateLimited {
+            retry_after: Some(wait),
+        } => {
+            assert!(wait > Duration::from_secs(100) && wait <= Duration::from_secs(120));
+        }
+        other => panic!("Expected RateLimited with a delay, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_exhausted_quota_pauses_group() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .rate_limiter(RateLimiterConfig::new())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Reset", "1")
+                .set_body_json(payment()),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(payment()))
+        .mount(&mock_server)
+        .await;
+
+    client.get_payment(1).await.unwrap();
+
+    let start = Instant::now();
+    client.get_payment(1).await.unwrap();
+    assert!(
+        start.elapsed() >= Duration::from_millis(900),
+        "group was not paused: {:?}",
+        start.elapsed()
+    );
+}
+
+#[test]
+fn test_non_positive_rates_are_rejected() {
+    for limit in [
+        RateLimit::per_second(0.0),
+        RateLimit::per_second(-1.0),
+        RateLimit::per_second(f64::NAN),
+        RateLimit::per_second(f64::INFINITY),
+        RateLimit::per_minute(0.0),
+    ] {
+        let result = MercadoPagoClient::builder("TEST_TOKEN")
+            .rate_limiter(RateLimiterConfig::new().group(EndpointGroup::Payments, limit))
+            .build();
+        match result {
+            Err(err) => assert_eq!(err.kind(), ErrorKind::Internal),
+            Ok(_) => panic!("Expected {:?} to be rejected", limit),
+        }
+    }
+}
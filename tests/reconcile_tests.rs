@@ -0,0 +1,201 @@
+#![cfg(feature = "testing")]
+
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::models::payments::PaymentResponse;
+use mercadopago_sdk::models::refunds::RefundResponse;
+use mercadopago_sdk::reconcile::{self, DateWindow, InMemorySource, LocalOrder, PaymentSource};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn payment(id: u64, reference: Option<&str>, status: &str, amount: f64) -> PaymentResponse {
+    serde_json::from_value(json!({
+        "id": id,
+        "status": status,
+        "transaction_amount": amount,
+        "currency_id": "ARS",
+        "external_reference": reference
+    }))
+    .unwrap()
+}
+
+fn refund(id: u64, payment_id: u64, amount: f64) -> RefundResponse {
+    RefundResponse {
+        id,
+        payment_id,
+        amount,
+        status: "approved".to_string(),
+        date_created: None,
+    }
+}
+
+fn window() -> DateWindow {
+    DateWindow::new(
+        "2026-10-01T00:00:00.000-03:00",
+        "2026-10-02T00:00:00.000-03:00",
+    )
+}
+
+fn source() -> InMemorySource {
+    let mut partial = payment(5, Some("PARTIAL"), "approved", 100.0);
+    partial.status_detail = Some("partially_refunded".to_string());
+
+    InMemorySource::new()
+        .payment(payment(2, Some("MATCHED"), "rejected", 100.0))
+        .payment(payment(1, Some("MATCHED"), "approved", 100.0))
+        .payment(payment(3, Some("MISMATCH"), "approved", 99.99))
+        .payment(payment(4, Some("DUP"), "approved", 50.0))
+        .payment(payment(6, Some("DUP"), "approved", 50.0))
+        .payment(partial)
+        .payment(payment(7, Some("REFUNDED"), "refunded", 30.0))
+        .payment(payment(8, Some("NOT-OURS"), "approved", 10.0))
+        .payment(payment(9, None, "approved", 20.0))
+        .payment(payment(10, Some("PENDING"), "pending", 10.0))
+        .refund(refund(100, 5, 40.0))
+        .refund(refund(101, 7, 30.0))
+}
+
+fn orders() -> Vec<LocalOrder> {
+    vec![
+        LocalOrder::new("REFUNDED", 30.0, "ARS"),
+        LocalOrder::new("PENDING", 10.0, "ARS"),
+        LocalOrder::new("MATCHED", 100.0, "ARS"),
+        LocalOrder::new("MISMATCH", 100.0, "ARS"),
+        LocalOrder::new("DUP", 50.0, "ARS"),
+        LocalOrder::new("PARTIAL", 100.0, "ARS"),
+    ]
+}
+
+#[tokio::test]
+async fn test_reconcile_classifies_orders_and_payments() {
+    let report = reconcile::reconcile(&source(), orders(), &window())
+        .await
+        .unwrap();
+
+    assert_eq!(report.matched.len(), 1);
+    assert_eq!(report.matched[0].payment.id, 1);
+
+    assert_eq!(report.amount_mismatches.len(), 1);
+    assert_eq!(
+        report.amount_mismatches[0].payment.transaction_amount,
+        99.99
+    );
+
+    assert_eq!(
+        report.missing_remotely,
+        vec![LocalOrder::new("PENDING", 10.0, "ARS")]
+    );
+
+    assert_eq!(report.duplicates.len(), 1);
+    let duplicate_ids: Vec<u64> = report.duplicates[0].payments.iter().map(|p| p.id).collect();
+    assert_eq!(duplicate_ids, vec![4, 6]);
+
+    assert_eq!(report.partially_refunded.len(), 1);
+    assert_eq!(report.partially_refunded[0].refunded_amount, 40.0);
+
+    assert_eq!(report.refunded.len(), 1);
+    assert_eq!(report.refunded[0].payment.id, 7);
+
+    let unknown_ids: Vec<u64> = report.unknown_remote.iter().map(|p| p.id).collect();
+    assert_eq!(unknown_ids, vec![9, 8]);
+
+    assert!(!report.is_clean());
+}
+
+#[tokio::test]
+async fn test_reconcile_is_deterministic() {
+    let first = reconcile::reconcile(&source(), orders(), &window())
+        .await
+        .unwrap();
+
+    let mut reversed = orders();
+    reversed.reverse();
+    let second = reconcile::reconcile(&source(), reversed, &window())
+        .await
+        .unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn test_reconcile_reports_orders_sharing_a_reference() {
+    let source = InMemorySource::new().payment(payment(1, Some("A"), "approved", 100.0));
+    let orders = vec![
+        LocalOrder::new("A", 100.0, "ARS"),
+        LocalOrder::new("A", 25.0, "ARS"),
+    ];
+    let report = reconcile::reconcile(&source, orders, &window())
+        .await
+        .unwrap();
+
+    assert_eq!(report.matched.len(), 1);
+    assert_eq!(report.matched[0].order.amount, 100.0);
+    assert_eq!(
+        report.duplicate_orders,
+        vec![LocalOrder::new("A", 25.0, "ARS")]
+    );
+    assert!(!report.is_clean());
+}
+
+#[tokio::test]
+async fn test_reconcile_currency_mismatch() {
+    let source = InMemorySource::new().payment(payment(1, Some("A"), "approved", 100.0));
+    let report = reconcile::reconcile(&source, vec![LocalOrder::new("A", 100.0, "BRL")], &window())
+        .await
+        .unwrap();
+
+    assert_eq!(report.amount_mismatches.len(), 1);
+}
+
+#[tokio::test]
+async fn test_client_source_paginates_payments() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    let page = |offset: u32, ids: &[u64]| {
+        json!({
+            "paging": { "total": 150, "offset": offset, "limit": 100 },
+            "results": ids.iter().map(|id| json!({
+                "id": id,
+                "status": "approved",
+                "transaction_amount": 10.0,
+                "external_reference": format!("ORDER-{}", id)
+            })).collect::<Vec<_>>()
+        })
+    };
+
+    let first: Vec<u64> = (1..=100).collect();
+    let second: Vec<u64> = (101..=150).collect();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .and(query_param("range", "date_created"))
+        .and(query_param("begin_date", "2026-10-01T00:00:00.000-03:00"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(0, &first)))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .and(query_param("offset", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(100, &second)))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    // Fetched twice: once directly and once by `reconcile`.
+    let payments = client.payments(&window()).await.unwrap();
+    assert_eq!(payments.len(), 150);
+
+    let orders: Vec<LocalOrder> = (1..=150)
+        .map(|id| LocalOrder::new(&format!("ORDER-{}", id), 10.0, "ARS"))
+        .collect();
+    let report = client.reconcile(orders, &window()).await.unwrap();
+    assert_eq!(report.matched.len(), 150);
+    assert!(report.is_clean());
+}
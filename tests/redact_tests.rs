@@ -0,0 +1,145 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::credentials::{SecretString, StaticCredentials};
+use mercadopago_sdk::models::oauth::OAuthToken;
+use mercadopago_sdk::models::payments::{Identification, Payer};
+use mercadopago_sdk::models::preferences::{Phone, PreferencePayer};
+use mercadopago_sdk::redact;
+use serde_json::json;
+use tokio::sync::Mutex;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Serializes tests, since the redaction switch is process-wide.
+static REDACTION: Mutex<()> = Mutex::const_new(());
+
+fn payer() -> Payer {
+    Payer {
+        email: "john.doe@gmail.com".to_string(),
+        identification: Some(Identification {
+            id_type: "DNI".to_string(),
+            number: "12345678".to_string(),
+        }),
+        first_name: Some("John".to_string()),
+        last_name: Some("Doe".to_string()),
+        address: None,
+    }
+}
+
+#[test]
+fn test_models_debug_masks_personal_data() {
+    let _lock = REDACTION.blocking_lock();
+
+    let debug = format!("{:?}", payer());
+    assert!(debug.contains(r#"email: "j***@gmail.com""#), "{}", debug);
+    assert!(debug.contains(r#"number: "****5678""#), "{}", debug);
+    assert!(debug.contains(r#"first_name: Some("J***")"#), "{}", debug);
+    assert!(!debug.contains("john.doe") && !debug.contains("12345678"));
+
+    let preference_payer = PreferencePayer {
+        email: Some("ana@example.com".to_string()),
+        phone: Some(Phone {
+            area_code: Some("11".to_string()),
+            number: Some("45556666".to_string()),
+        }),
+        ..Default::default()
+    };
+    let debug = format!("{:?}", preference_payer);
+    assert!(debug.contains(r#"Some("a***@example.com")"#), "{}", debug);
+    assert!(debug.contains(r#"area_code: Some("11"), number: Some("****6666")"#));
+}
+
+#[test]
+fn test_secrets_are_never_printed() {
+    let _lock = REDACTION.blocking_lock();
+    redact::set_pii_redaction(false);
+
+    let token = OAuthToken {
+        access_token: SecretString::from("APP_USR-secret"),
+        refresh_token: Some(SecretString::from("TG-secret")),
+        expires_in: 3600,
+        user_id: 1,
+        scope: None,
+        token_type: None,
+        public_key: None,
+        live_mode: None,
+    };
+    let debug = format!("{:?}", token);
+    let credentials = format!("{:?}", StaticCredentials::new("APP_USR-secret"));
+    // Opting out of PII masking still shows personal data.
+    let payer = format!("{:?}", payer());
+
+    redact::set_pii_redaction(true);
+    assert!(!debug.contains("secret"), "{}", debug);
+    assert!(debug.contains(r#"access_token: "[REDACTED]""#), "{}", debug);
+    assert!(!credentials.contains("APP_USR"), "{}", credentials);
+    assert!(payer.contains("john.doe@gmail.com"), "{}", payer);
+}
+
+#[tokio::test]
+async fn test_error_bodies_are_masked() {
+    let _lock = REDACTION.lock().await;
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "status": "approved",
+            "transaction_amount": "ten",
+            "payer": {
+                "email": "john.doe@gmail.com",
+                "identification": { "type": "DNI", "number": "12345678" }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/2"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": "invalid payer",
+            "payer": { "email": "john.doe@gmail.com" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let decode_error = client.get_payment(1).await.unwrap_err();
+    let api_error = client.get_payment(2).await.unwrap_err();
+
+    let message = decode_error.to_string();
+    assert!(message.contains("transaction_amount"), "{}", message);
+    assert!(message.contains("j***@gmail.com"), "{}", message);
+    assert!(message.contains("****5678"), "{}", message);
+
+    let debug = format!("{:?}", api_error);
+    assert!(debug.contains("j***@gmail.com"), "{}", debug);
+    assert!(!debug.contains("john.doe"), "{}", debug);
+}
+
+#[tokio::test]
+async fn test_truncated_bodies_are_masked() {
+    let _lock = REDACTION.lock().await;
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"{"id": 1, "status": "approved", "payer": {"email": "john.doe@gmail.com", "first_name": "John", "identification": {"number": "12345"#,
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let message = client.get_payment(1).await.unwrap_err().to_string();
+    assert!(message.contains("Invalid JSON response"), "{}", message);
+    assert!(message.contains("j***@gmail.com"), "{}", message);
+    assert!(!message.contains("john.doe"), "{}", message);
+    assert!(!message.contains("John"), "{}", message);
+    assert!(!message.contains("12345"), "{}", message);
+}
@@ -0,0 +1,156 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::reports::{
+    self, ReleaseRow, ReportColumn, ReportConfig, ReportKind, SettlementRow,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SETTLEMENT_CSV: &str = "\u{feff}EXTERNAL_REFERENCE,SOURCE_ID,USER_ID,TRANSACTION_TYPE,TRANSACTION_AMOUNT,FEE_AMOUNT,SETTLEMENT_NET_AMOUNT,SETTLEMENT_DATE,INSTALLMENTS\n\
+ORDER-1,111,999,SETTLEMENT,100.00,-4.99,95.01,2026-10-15T10:00:00.000-03:00,1\n\
+ORDER-2,222,999,REFUND,-50.00,,-50.00,2026-10-15T12:00:00.000-03:00,\n";
+
+#[test]
+fn test_parse_settlement_csv() {
+    let rows: Vec<SettlementRow> = reports::parse_csv(SETTLEMENT_CSV)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].external_reference.as_deref(), Some("ORDER-1"));
+    assert_eq!(rows[0].source_id.as_deref(), Some("111"));
+    assert_eq!(rows[0].transaction_type.as_deref(), Some("SETTLEMENT"));
+    assert_eq!(rows[0].transaction_amount, Some(100.0));
+    assert_eq!(rows[0].fee_amount, Some(-4.99));
+    assert_eq!(rows[0].settlement_net_amount, Some(95.01));
+    assert_eq!(rows[0].installments, Some(1));
+    assert_eq!(rows[1].fee_amount, None);
+    assert_eq!(rows[1].installments, None);
+}
+
+#[test]
+fn test_parse_release_csv_with_semicolons_and_bad_row() {
+    let csv = "DATE;SOURCE_ID;RECORD_TYPE;DESCRIPTION;NET_CREDIT_AMOUNT;NET_DEBIT_AMOUNT\n\
+               2026-10-15;111;release;payment;95.01;0.00\n\
+               2026-10-15;222;release;refund;not-a-number;50.00\n";
+
+    let rows: Vec<Result<ReleaseRow, _>> = reports::parse_csv(csv).collect();
+    assert_eq!(rows.len(), 2);
+    let first = rows[0].as_ref().unwrap();
+    assert_eq!(first.description.as_deref(), Some("payment"));
+    assert_eq!(first.net_credit_amount, Some(95.01));
+    assert!(matches!(
+        rows[1],
+        Err(mercadopago_sdk::error::Error::Serialization(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_settlement_report_lifecycle() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+    let settlement = client.reports(ReportKind::Settlement);
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/account/settlement_report/config"))
+        .and(body_partial_json(json!({
+            "file_name_prefix": "settlement",
+            "columns": [{ "key": "SOURCE_ID" }, { "key": "FEE_AMOUNT" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "file_name_prefix": "settlement",
+            "columns": [{ "key": "SOURCE_ID" }, { "key": "FEE_AMOUNT" }],
+            "scheduled": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/account/settlement_report"))
+        .and(body_json(json!({
+            "begin_date": "2026-10-01T00:00:00Z",
+            "end_date": "2026-10-02T00:00:00Z"
+        })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/account/settlement_report/list"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": 1,
+            "file_name": "settlement-2026-10-02.csv",
+            "begin_date": "2026-10-01T00:00:00Z",
+            "end_date": "2026-10-02T00:00:00Z",
+            "created_from": "manual"
+        }])))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/v1/account/settlement_report/settlement-2026-10-02.csv",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string(SETTLEMENT_CSV))
+        .mount(&mock_server)
+        .await;
+
+    let config = settlement
+        .update_config(&ReportConfig {
+            file_name_prefix: Some("settlement".to_string()),
+            columns: Some(vec![
+                ReportColumn {
+                    key: "SOURCE_ID".to_string(),
+                },
+                ReportColumn {
+                    key: "FEE_AMOUNT".to_string(),
+                },
+            ]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(config.scheduled, Some(false));
+
+    settlement
+        .create("2026-10-01T00:00:00Z", "2026-10-02T00:00:00Z")
+        .await
+        .unwrap();
+
+    let files = settlement.list().await.unwrap();
+    assert_eq!(files[0].file_name, "settlement-2026-10-02.csv");
+
+    let csv = settlement.download(&files[0].file_name).await.unwrap();
+    let rows: Vec<SettlementRow> = reports::parse_csv(&csv).collect::<Result<_, _>>().unwrap();
+    assert_eq!(rows.len(), 2);
+}
+
+#[tokio::test]
+async fn test_release_report_config() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/account/release_report/config"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "file_name_prefix": "release",
+            "frequency": { "type": "daily", "value": 0, "hour": 6 },
+            "execute_after_withdrawal": true
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let config = client.reports(ReportKind::Release).config().await.unwrap();
+    let frequency = config.frequency.unwrap();
+    assert_eq!(frequency.frequency_type, "daily");
+    assert_eq!(frequency.hour, 6);
+    assert_eq!(config.execute_after_withdrawal, Some(true));
+}
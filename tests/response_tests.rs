@@ -0,0 +1,125 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::error::Error;
+use mercadopago_sdk::models::payments::PaymentResponse;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup() -> (MockServer, MercadoPagoClient) {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+    (mock_server, client)
+}
+
+#[tokio::test]
+async fn test_get_payment_with_response_exposes_metadata() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-request-id", "req-abc")
+                .insert_header("x-ratelimit-remaining", "99")
+                .set_body_json(json!({
+                    "id": 123,
+                    "status": "approved",
+                    "transaction_amount": 100.0,
+                    "brand_new_field": { "nested": true }
+                })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let res = client.get_payment_with_response(123).await.unwrap();
+
+    assert_eq!(res.data.id, 123);
+    assert_eq!(res.status, 200);
+    assert_eq!(res.request_id(), Some("req-abc"));
+    assert_eq!(res.header("x-ratelimit-remaining"), Some("99"));
+    assert_eq!(res.raw["brand_new_field"]["nested"], json!(true));
+}
+
+#[tokio::test]
+async fn test_send_json_reports_path_of_decode_error() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123,
+            "status": "approved",
+            "transaction_amount": 100.0,
+            "transaction_details": { "net_received_amount": "not-a-number" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .get("/v1/payments/123")
+        .send_json::<PaymentResponse>()
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::Serialization(source) => {
+            let msg = source.to_string();
+            assert!(
+                msg.contains("transaction_details.net_received_amount"),
+                "missing path: {}",
+                msg
+            );
+            assert!(msg.contains("not-a-number"), "missing snippet: {}", msg);
+            // The serde_json error stays reachable through the source chain.
+            assert!(source.source().is_some());
+        }
+        other => panic!("Expected Serialization, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_send_json_truncates_invalid_body() {
+    let (mock_server, client) = setup().await;
+
+    let body = format!("<html>{}</html>", "x".repeat(1_000));
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(body))
+        .mount(&mock_server)
+        .await;
+
+    let err = client
+        .get("/v1/payments/123")
+        .send_json::<PaymentResponse>()
+        .await
+        .unwrap_err();
+
+    match err {
+        Error::Serialization(source) => {
+            let msg = source.to_string();
+            assert!(msg.contains("<html>"), "missing snippet: {}", msg);
+            assert!(msg.len() < 400, "snippet not truncated: {}", msg);
+        }
+        other => panic!("Expected Serialization, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_typed_methods_report_path_of_decode_error() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123/refunds"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "id": 1, "payment_id": 123, "amount": "ten", "status": "approved" }
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let err = client.list_refunds(123).await.unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("[0].amount"), "missing path: {}", msg);
+}
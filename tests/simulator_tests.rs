@@ -0,0 +1,344 @@
+#![cfg(feature = "testing")]
+
+use hmac::{Hmac, Mac};
+use mercadopago_sdk::models::instore::{PosRequest, QrOrderItem, QrOrderRequest, StoreRequest};
+use mercadopago_sdk::models::payments::{Payer, PaymentRequest};
+use mercadopago_sdk::models::preferences::{PreferenceItem, PreferenceRequest};
+use mercadopago_sdk::models::refunds::RefundRequest;
+use mercadopago_sdk::testing::{Fault, Simulator};
+use mercadopago_sdk::{Error, ErrorKind, MercadoPagoClient};
+use serde_json::json;
+use sha2::Sha256;
+use std::time::Duration;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn payer() -> Payer {
+    Payer {
+        email: "buyer@example.com".to_string(),
+        ..Default::default()
+    }
+}
+
+fn causes(err: &Error) -> Vec<String> {
+    match err.kind() {
+        ErrorKind::Validation { causes } => {
+            causes.into_iter().filter_map(|c| c.description).collect()
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+}
+
+async fn create_pos(client: &MercadoPagoClient, user_id: u64) {
+    let store = client
+        .create_store(
+            user_id,
+            StoreRequest {
+                name: "Sucursal Centro".to_string(),
+                external_id: "STORE001".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    client
+        .create_pos(PosRequest {
+            name: "Caja 1".to_string(),
+            store_id: store.id,
+            external_id: "POS001".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+fn qr_order(total_amount: f64) -> QrOrderRequest {
+    QrOrderRequest {
+        external_reference: Some("ORDER-1".to_string()),
+        title: Some("Café".to_string()),
+        total_amount,
+        items: vec![QrOrderItem {
+            title: "Café".to_string(),
+            unit_price: 750.0,
+            quantity: 2,
+            unit_measure: "unit".to_string(),
+            total_amount: 1500.0,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_simulator_qr_flow() {
+    let sim = Simulator::builder().collector_id(42).start().await;
+    let client = sim.client();
+
+    let user_id = client.user_id().await.unwrap();
+    assert_eq!(user_id, 42);
+    create_pos(&client, user_id).await;
+    assert_eq!(client.search_stores(user_id).await.unwrap().paging.total, 1);
+    assert_eq!(client.list_pos().await.unwrap().results[0].name, "Caja 1");
+
+    // Creating the same store twice conflicts.
+    let err = client
+        .create_store(
+            user_id,
+            StoreRequest {
+                name: "Otra".to_string(),
+                external_id: "STORE001".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Conflict);
+
+    let err = client
+        .create_qr_order(user_id, "POS001", qr_order(1000.0))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        causes(&err),
+        ["total_amount must be the sum of the items total_amount"]
+    );
+
+    let order = client
+        .create_qr_order(user_id, "POS001", qr_order(1500.0))
+        .await
+        .unwrap();
+    assert!(order.emvco_payload().unwrap().matches_amount(1500.0));
+
+    let payment_id = sim.pay_qr_order("POS001");
+    let payment = client.get_payment(payment_id).await.unwrap();
+    assert_eq!(payment.status, "pending");
+    assert_eq!(payment.transaction_amount, 1500.0);
+    assert_eq!(payment.external_reference.as_deref(), Some("ORDER-1"));
+
+    sim.approve_payment(payment_id);
+    let found = client.search_payments("ORDER-1").await.unwrap();
+    assert_eq!(found.paging.total, 1);
+    assert_eq!(found.results[0].status, "approved");
+    assert_eq!(
+        found.results[0].status_detail.as_deref(),
+        Some("accredited")
+    );
+}
+
+#[tokio::test]
+async fn test_simulator_checkout_flow_with_refunds() {
+    let sim = Simulator::start().await;
+    let client = sim.client();
+
+    let preference = client
+        .create_preference(PreferenceRequest {
+            items: vec![PreferenceItem {
+                title: "Remera".to_string(),
+                quantity: 2,
+                unit_price: 100.0,
+                ..Default::default()
+            }],
+            external_reference: Some("CART-7".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(preference.init_point.ends_with(&preference.id));
+
+    let rejected = sim.pay_preference(&preference.id);
+    sim.reject_payment(rejected, "cc_rejected_insufficient_amount");
+    let approved = sim.pay_preference(&preference.id);
+    sim.approve_payment(approved);
+
+    let found = client
+        .search_payments_generic(&[("external_reference", "CART-7"), ("status", "approved")])
+        .await
+        .unwrap();
+    assert_eq!(found.paging.total, 1);
+    assert_eq!(found.results[0].id, approved);
+    assert_eq!(found.results[0].transaction_amount, 200.0);
+
+    let err = client
+        .create_refund(rejected, RefundRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        causes(&err),
+        ["Payment in status rejected cannot be refunded"]
+    );
+
+    let partial = client
+        .create_refund(approved, RefundRequest { amount: Some(50.0) })
+        .await
+        .unwrap();
+    assert_eq!(partial.amount, 50.0);
+    let payment = client.get_payment(approved).await.unwrap();
+    assert_eq!(payment.status, "approved");
+    assert_eq!(payment.status_detail.as_deref(), Some("partially_refunded"));
+
+    let err = client
+        .create_refund(
+            approved,
+            RefundRequest {
+                amount: Some(500.0),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Validation { .. }));
+
+    let rest = client
+        .create_refund(approved, RefundRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(rest.amount, 150.0);
+    assert_eq!(client.list_refunds(approved).await.unwrap().len(), 2);
+    assert_eq!(
+        client.get_payment(approved).await.unwrap().status,
+        "refunded"
+    );
+}
+
+#[tokio::test]
+async fn test_simulator_validates_payments() {
+    let sim = Simulator::start().await;
+    let client = sim.client();
+
+    let err = client
+        .create_payment(PaymentRequest {
+            transaction_amount: 0.0,
+            payment_method_id: "pix".to_string(),
+            payer: Payer {
+                email: "not-an-email".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    let Error::ApiError(api) = &err else {
+        panic!("expected an API error, got {:?}", err);
+    };
+    assert_eq!(api.status, 400);
+    assert_eq!(api.error, "bad_request");
+    assert_eq!(api.cause[0].code, Some(json!(4037)));
+    assert_eq!(api.cause[1].code, Some(json!(4050)));
+
+    let err = client
+        .create_payment(PaymentRequest {
+            transaction_amount: 100.0,
+            payment_method_id: "bolbradesco".to_string(),
+            payer: payer(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(causes(&err)[0].starts_with("Payer is missing fields required by bolbradesco"));
+
+    let err = client.get_payment(1).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+
+    // The same idempotency key returns the same payment.
+    let request = PaymentRequest::pix(100.0, payer());
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let payment: mercadopago_sdk::models::payments::PaymentResponse = client
+            .post("/v1/payments")
+            .idempotency_key("KEY-1")
+            .unwrap()
+            .json(&request)
+            .send_json()
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(payment.status, "pending");
+        ids.push(payment.id);
+    }
+    assert_eq!(ids[0], ids[1]);
+}
+
+#[tokio::test]
+async fn test_simulator_faults_and_auth() {
+    let sim = Simulator::builder()
+        .access_token("TEST-SELLER")
+        .start()
+        .await;
+    let client = sim.client();
+
+    sim.inject_fault(
+        Fault::RateLimited {
+            retry_after: Duration::ZERO,
+        },
+        2,
+    );
+    let payment = client
+        .create_payment(PaymentRequest::pix(100.0, payer()))
+        .await
+        .unwrap();
+    assert_eq!(client.stats().retries, 2);
+
+    sim.inject_fault(Fault::ServerError(503), 1);
+    let err = client.get_payment(payment.id).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Server);
+    client.get_payment(payment.id).await.unwrap();
+
+    let other = MercadoPagoClient::builder("TEST-OTHER")
+        .base_url(&sim.uri())
+        .build()
+        .unwrap();
+    let err = other.get_me().await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unauthorized);
+}
+
+#[tokio::test]
+async fn test_simulator_fires_signed_webhooks() {
+    let sim = Simulator::builder().webhook_secret("s3cr3t").start().await;
+    let client = sim.client();
+    let payment = client
+        .create_payment(PaymentRequest::pix(100.0, payer()))
+        .await
+        .unwrap();
+    sim.approve_payment(payment.id);
+
+    let receiver = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/webhooks"))
+        .and(query_param("data.id", payment.id.to_string()))
+        .and(query_param("type", "payment"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&receiver)
+        .await;
+
+    let status = sim
+        .fire_webhook(&format!("{}/webhooks", receiver.uri()), payment.id)
+        .await
+        .unwrap();
+    assert_eq!(status, 200);
+
+    let request = &receiver.received_requests().await.unwrap()[0];
+    let body: serde_json::Value = request.body_json().unwrap();
+    assert_eq!(body["action"], "payment.updated");
+    assert_eq!(body["data"]["id"], payment.id.to_string());
+
+    let header = |name: &str| request.headers.get(name).unwrap().to_str().unwrap();
+    let (ts, v1) = header("x-signature")
+        .split_once(',')
+        .map(|(ts, v1)| (&ts[3..], &v1[3..]))
+        .unwrap();
+    let manifest = format!(
+        "id:{};request-id:{};ts:{};",
+        payment.id,
+        header("x-request-id"),
+        ts
+    );
+    let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cr3t").unwrap();
+    mac.update(manifest.as_bytes());
+    let expected: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    assert_eq!(v1, expected);
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "logging")]
+
+use mercadopago_sdk::MercadoPagoClient;
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Instrument, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Collects the fields of `mercadopago.request` spans.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<HashMap<String, String>>>);
+
+struct Visitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Visitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Capture {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        if attrs.metadata().name() == "mercadopago.request" {
+            attrs.record(&mut Visitor(&mut self.0.lock().unwrap()));
+        }
+    }
+
+    fn on_record(&self, _id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        values.record(&mut Visitor(&mut self.0.lock().unwrap()));
+    }
+}
+
+#[tokio::test]
+async fn test_request_span_attributes() {
+    let capture = Capture::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123456"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/123456"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-request-id", "req-42")
+                .set_body_json(
+                    json!({ "id": 123456, "status": "approved", "transaction_amount": 10.0 }),
+                ),
+        )
+        .mount(&mock_server)
+        .await;
+
+    client.get_payment(123456).await.unwrap();
+
+    let fields = capture.0.lock().unwrap().clone();
+    assert_eq!(fields["otel.name"], "GET /v1/payments/{id}");
+    assert_eq!(fields["url.template"], "/v1/payments/{id}");
+    assert_eq!(fields["http.request.method"], "GET");
+    assert_eq!(fields["http.response.status_code"], "200");
+    assert_eq!(fields["http.request.resend_count"], "1");
+    assert_eq!(fields["mercadopago.endpoint_group"], "payments");
+    assert_eq!(fields["mercadopago.idempotency_key"], "false");
+    assert_eq!(fields["mercadopago.request_id"], "req-42");
+}
+
+#[tokio::test]
+async fn test_request_span_records_errors_and_idempotency_key() {
+    let capture = Capture::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/orders/ORD01ABC/cancel"))
+        .respond_with(
+            ResponseTemplate::new(409)
+                .insert_header("x-request-id", "req-409")
+                .set_body_json(json!({ "message": "order already processed" })),
+        )
+        .mount(&mock_server)
+        .await;
+
+    client
+        .cancel_order("ORD01ABC", "key-1")
+        .instrument(tracing::info_span!("checkout"))
+        .await
+        .unwrap_err();
+
+    let fields = capture.0.lock().unwrap().clone();
+    assert_eq!(fields["otel.name"], "POST /v1/orders/{id}/cancel");
+    assert_eq!(fields["mercadopago.idempotency_key"], "true");
+    assert_eq!(fields["http.response.status_code"], "409");
+    assert_eq!(fields["otel.status_code"], "ERROR");
+    assert_eq!(fields["error.type"], "409");
+    assert_eq!(fields["mercadopago.request_id"], "req-409");
+}
+
+#[cfg(feature = "otel")]
+#[tokio::test]
+async fn test_traceparent_is_injected() {
+    use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+    let provider = SdkTracerProvider::builder().build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("tests")));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 1 })))
+        .mount(&mock_server)
+        .await;
+
+    let checkout = tracing::info_span!("checkout");
+    let trace_id = checkout.context().span().span_context().trace_id();
+    client.get_me().instrument(checkout).await.unwrap();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let traceparent = requests[0]
+        .headers
+        .get("traceparent")
+        .expect("traceparent header")
+        .to_str()
+        .unwrap();
+    let parts: Vec<&str> = traceparent.split('-').collect();
+    assert_eq!(parts.len(), 4);
+    assert_eq!(parts[0], "00");
+    assert_eq!(parts[1], trace_id.to_string());
+    assert_eq!(parts[3], "01");
+}
@@ -0,0 +1,89 @@
+use mercadopago_sdk::MercadoPagoClient;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup() -> (MockServer, MercadoPagoClient) {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+    (mock_server, client)
+}
+
+#[tokio::test]
+async fn test_get_me_and_cached_user_id() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 123456789,
+            "nickname": "TESTUSER123",
+            "email": "test_user_123@testuser.com",
+            "site_id": "MLA",
+            "country_id": "AR"
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let me = client.get_me().await.unwrap();
+    assert_eq!(me.id, 123456789);
+    assert_eq!(me.site_id.as_deref(), Some("MLA"));
+
+    // Resolved once, then shared by every clone.
+    assert_eq!(client.user_id().await.unwrap(), 123456789);
+    assert_eq!(client.clone().user_id().await.unwrap(), 123456789);
+}
+
+#[tokio::test]
+async fn test_user_id_error_is_not_cached() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "message": "invalid access token",
+            "error": "unauthorized",
+            "status": 401
+        })))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "id": 42 })))
+        .mount(&mock_server)
+        .await;
+
+    assert!(client.user_id().await.is_err());
+    assert_eq!(client.user_id().await.unwrap(), 42);
+}
+
+#[tokio::test]
+async fn test_create_test_user() {
+    let (mock_server, client) = setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/users/test_user"))
+        .and(body_json(
+            json!({ "site_id": "MLB", "description": "seller" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": 987654321,
+            "nickname": "TESTABC123",
+            "password": "qatest1234",
+            "site_status": "active",
+            "email": "test_user_987@testuser.com"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let user = client.create_test_user("MLB", "seller").await.unwrap();
+    assert_eq!(user.id, 987654321);
+    assert_eq!(user.password, "qatest1234");
+}
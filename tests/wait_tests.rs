@@ -0,0 +1,295 @@
+use mercadopago_sdk::MercadoPagoClient;
+use mercadopago_sdk::wait::{PaymentOutcome, WaitOptions};
+use serde_json::json;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn fast_options() -> WaitOptions {
+    WaitOptions::new()
+        .initial_interval(Duration::from_millis(10))
+        .max_interval(Duration::from_millis(20))
+}
+
+fn search_body(status: &str) -> serde_json::Value {
+    json!({
+        "paging": { "total": 1, "offset": 0, "limit": 30 },
+        "results": [{
+            "id": 111,
+            "status": status,
+            "transaction_amount": 50.0,
+            "external_reference": "REF-001"
+        }]
+    })
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_approved_after_pending() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .and(query_param("external_reference", "REF-001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(search_body("pending")))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .and(query_param("external_reference", "REF-001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(search_body("approved")))
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client
+        .wait_for_payment("REF-001", fast_options())
+        .await
+        .unwrap();
+    match outcome {
+        PaymentOutcome::Approved(payment) => assert_eq!(payment.id, 111),
+        other => panic!("Expected Approved, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_rejected() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(search_body("rejected")))
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client
+        .wait_for_payment("REF-001", fast_options())
+        .await
+        .unwrap();
+    assert!(matches!(outcome, PaymentOutcome::Rejected(_)));
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_times_out_and_skips_transient_errors() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(503).set_body_json(json!({
+            "message": "Service unavailable",
+            "status": 503
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let outcome = client
+        .wait_for_payment(
+            "REF-001",
+            fast_options().timeout(Duration::from_millis(100)),
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome, PaymentOutcome::TimedOut);
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_stops_on_client_error() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "message": "Invalid access token",
+            "error": "unauthorized",
+            "status": 401
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let res = client.wait_for_payment("REF-001", fast_options()).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_cancelled() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(search_body("pending")))
+        .mount(&mock_server)
+        .await;
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+
+    let outcome = client
+        .wait_for_payment("REF-001", fast_options().cancellation_token(token))
+        .await
+        .unwrap();
+    assert_eq!(outcome, PaymentOutcome::Cancelled);
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_notification() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "paging": { "total": 0, "offset": 0, "limit": 30 },
+            "results": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/222"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 222,
+            "status": "approved",
+            "transaction_amount": 50.0,
+            "external_reference": "REF-001"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let (tx, rx) = broadcast::channel(8);
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        tx.send(222).unwrap();
+    });
+
+    // Polling alone would never find the payment before the deadline.
+    let options = WaitOptions::new()
+        .initial_interval(Duration::from_secs(60))
+        .timeout(Duration::from_secs(5))
+        .notifications(rx);
+
+    let outcome = client.wait_for_payment("REF-001", options).await.unwrap();
+    match outcome {
+        PaymentOutcome::Approved(payment) => assert_eq!(payment.id, 222),
+        other => panic!("Expected Approved, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_id_after_challenge() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/222"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 222,
+            "status": "pending",
+            "status_detail": "pending_challenge",
+            "transaction_amount": 50.0,
+            "three_ds_info": {
+                "external_resource_url": "https://acs.example.com/challenge",
+                "creq": "eyJjcmVxIjoiMSJ9"
+            }
+        })))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/222"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 222,
+            "status": "rejected",
+            "status_detail": "cc_rejected_3ds_challenge",
+            "transaction_amount": 50.0
+        })))
+        .mount(&mock_server)
+        .await;
+
+    match client
+        .wait_for_payment_id(222, fast_options())
+        .await
+        .unwrap()
+    {
+        PaymentOutcome::Rejected(payment) => {
+            assert_eq!(
+                payment.status_detail.as_deref(),
+                Some("cc_rejected_3ds_challenge")
+            );
+        }
+        other => panic!("Expected Rejected, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_payment_keeps_waiting_after_rejected_retry() {
+    let mock_server = MockServer::start().await;
+    let client = MercadoPagoClient::builder("TEST_TOKEN")
+        .base_url(&mock_server.uri())
+        .build()
+        .unwrap();
+
+    let attempts = |retry_status: &str| {
+        json!({
+            "paging": { "total": 2, "offset": 0, "limit": 30 },
+            "results": [
+                { "id": 111, "status": "rejected", "transaction_amount": 50.0, "external_reference": "REF-001" },
+                { "id": 112, "status": retry_status, "transaction_amount": 50.0, "external_reference": "REF-001" }
+            ]
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(attempts("pending")))
+        .up_to_n_times(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/payments/search"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(attempts("approved")))
+        .mount(&mock_server)
+        .await;
+
+    match client
+        .wait_for_payment("REF-001", fast_options())
+        .await
+        .unwrap()
+    {
+        PaymentOutcome::Approved(payment) => assert_eq!(payment.id, 112),
+        other => panic!("Expected Approved, got {:?}", other),
+    }
+}